@@ -95,7 +95,7 @@ if args.format:
 if args.test:
 	crates_no_examples = filter(lambda crate: crate != 'examples', crate_list)
 	for crate in crates_no_examples:
-		check_call(cargo_cmd('test'), cwd=crate)
+		check_call(cargo_cmd('test', '--all-features'), cwd=crate)
 		check_call(cargo_cmd('fmt', '--check'), cwd=crate)
 
 if args.test_outputs or args.make_golden_outputs:
@@ -0,0 +1,65 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let fruits = ["Apples", "Oranges", "Bananas", "Pears"];
+
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_title("Bar chart", &[])
+		.bar(&fruits, [3, 5, 2, 4], &[Color("#4477aa".into())])
+		.lines_categorical(
+			&fruits,
+			[2.5, 4.0, 3.0, 3.5],
+			&[Color("black".into()), LineWidth(2.0)],
+		);
+
+	c.show(&mut fg, "bars_1");
+
+	let mut fg = Figure::new();
+	fg.axes2d().set_title("Grouped bars", &[]).bars(
+		&fruits,
+		[[3, 5, 2, 4], [1, 2, 3, 4], [4, 1, 1, 2]],
+		GroupedBars,
+		&[
+			&[Caption("2022"), Color("#4477aa".into())],
+			&[Caption("2023"), Color("#ee6677".into())],
+			&[Caption("2024"), Color("#228833".into())],
+		],
+	);
+
+	c.show(&mut fg, "bars_2");
+
+	let months = ["January", "February", "March", "April", "May", "June"];
+
+	let mut fg = Figure::new();
+	fg.axes2d().set_title("Stacked bars", &[]).bars(
+		&months,
+		[
+			[3.0, 5.0, 2.0, 4.0, 1.0, 2.0],
+			[1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+			[-1.0, -2.0, 1.0, -1.0, 2.0, -3.0],
+		],
+		StackedBars,
+		&[
+			&[Caption("Income"), Color("#4477aa".into())],
+			&[Caption("Savings"), Color("#228833".into())],
+			&[
+				Caption("Balance"),
+				Color("#ee6677".into()),
+				BorderColor("black".into()),
+			],
+		],
+	);
+
+	c.show(&mut fg, "bars_3");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
@@ -0,0 +1,47 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let mut x = vec![];
+	let mut y = vec![];
+	let mut z = vec![];
+	let mut colors = vec![];
+	for i in 0..5
+	{
+		for j in 0..5
+		{
+			let (fi, fj) = (i as f64 - 2.0, j as f64 - 2.0);
+			x.push(i);
+			y.push(j);
+			z.push((-(fi * fi + fj * fj) / 4.0).exp());
+			colors.push(j as u8 + 1);
+		}
+	}
+
+	let mut fg = Figure::new();
+
+	fg.axes3d()
+		.set_title("3D boxes", &[])
+		.boxes(
+			&x,
+			&y,
+			&z,
+			&[Color(VariableIndex(colors)), BorderColor("black".into())],
+		)
+		.set_box_width(0.6, false)
+		.set_box_depth(0.6)
+		.set_pm3d_lighting(0.5, 0.2)
+		.set_xy_plane(XYPlaneRelative(0.0))
+		.set_view(60.0, 30.0);
+
+	c.show(&mut fg, "boxes_3d");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
@@ -0,0 +1,48 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let x: Vec<f64> = (0..50).map(|i| i as f64 / 5.0).collect();
+	let y: Vec<f64> = x
+		.iter()
+		.map(|&x| {
+			if (4.0..5.0).contains(&x)
+			{
+				100.0 + x
+			}
+			else
+			{
+				x.sin()
+			}
+		})
+		.collect();
+
+	let mut fg = Figure::new();
+	let mut axes = fg.broken_axes(BrokenY, &[(-1.5, 1.5), (100.0, 106.0)]);
+	axes.plot(|a| {
+		a.lines(&x, &y, &[Caption("Signal"), Color("black".into())]);
+	});
+	axes.panels()[1].set_title("Broken Y axis", &[]);
+
+	c.show(&mut fg, "broken_axes_1");
+
+	let mut fg = Figure::new();
+	let mut axes = fg.broken_axes(BrokenX, &[(0.0, 3.0), (7.0, 10.0)]);
+	axes.plot(|a| {
+		a.points(&x, &y, &[PointSymbol('O'), Color("#4477aa".into())]);
+	});
+	axes.panels()[0]
+		.set_y_label("Value", &[])
+		.set_title("Broken X axis", &[]);
+
+	c.show(&mut fg, "broken_axes_2");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
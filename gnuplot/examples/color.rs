@@ -132,6 +132,22 @@ fn example(c: Common)
 		.set_y_range(Fix(-0.5), Fix(1.5))
 		.set_legend(Graph(0.45), Graph(0.9), &[], &[Font("", 12.0)]);
 	c.show(&mut fg, "palette_colors");
+
+	// ########################################################################
+
+	let mut fg = Figure::new();
+	let ax = fg.axes2d();
+	let x: Vec<f64> = (0..50).map(|i| i as f64 / 10.0).collect();
+	for (i, color) in palettes::VIRIDIS
+		.to_colors_in(6, InterpolateOKLab)
+		.into_iter()
+		.enumerate()
+	{
+		let y: Vec<f64> = x.iter().map(|&x| (x + i as f64 / 2.0).sin()).collect();
+		ax.lines(&x, &y, &[Color(color.to_ref()), LineWidth(2.0)]);
+	}
+	ax.set_title("Series colored from a palette", &[]);
+	c.show(&mut fg, "palette_sampled_colors");
 }
 
 fn main()
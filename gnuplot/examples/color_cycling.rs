@@ -23,6 +23,23 @@ fn example(c: Common)
 	}
 
 	c.show(&mut fg, "color_cycling");
+
+	let mut fg = Figure::new();
+
+	let ax = fg.axes2d();
+	ax.set_title("Style cycle", &[]);
+	ax.set_legend(Graph(0.2), Graph(0.9), &[], &[]);
+	ax.set_style_cycle(&StyleCycle::grayscale());
+	for i in 0..6
+	{
+		ax.lines_points(
+			x.clone(),
+			x.clone().map(|v| v * 2 + i),
+			&[Caption(&format!("{}", i))],
+		);
+	}
+
+	c.show(&mut fg, "color_cycling_2");
 }
 
 fn main()
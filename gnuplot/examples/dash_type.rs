@@ -13,10 +13,20 @@ fn example(c: Common)
 	let ax = fg.axes2d();
 	ax.set_title("Dash type", &[]);
 	ax.set_legend(Graph(0.3), Graph(0.9), &[], &[]);
-	for (i, &dt) in [Solid, SmallDot, Dot, Dash, DotDash, DotDotDash]
-		.iter()
-		.enumerate()
+	for (i, dt) in [
+		Solid,
+		SmallDot,
+		Dot,
+		Dash,
+		DotDash,
+		DotDotDash,
+		DashType::custom(&[(8., 4.), (1., 4.)]).unwrap(),
+		DashType::pattern("_ . ").unwrap(),
+	]
+	.into_iter()
+	.enumerate()
 	{
+		let caption = format!("{:?}", dt);
 		ax.lines(
 			x.clone(),
 			x.clone().map(|v| v * 2 + 2 * i),
@@ -24,12 +34,49 @@ fn example(c: Common)
 				LineWidth(2.),
 				Color("black".into()),
 				LineStyle(dt),
-				Caption(&format!("{:?}", dt)),
+				Caption(&caption),
 			],
 		);
 	}
 
 	c.show(&mut fg, "dash_type");
+
+	// A trajectory whose width and color follow its speed, dashed where it is slow.
+	let t: Vec<f64> = (0..200).map(|i| i as f64 / 20.).collect();
+	let x: Vec<f64> = t.iter().map(|&t| t.cos() * (1. + t / 5.)).collect();
+	let y: Vec<f64> = t.iter().map(|&t| t.sin() * (1. + t / 5.)).collect();
+	let speed: Vec<f64> = t.iter().map(|&t| 1. + (2. * t).sin().abs() * 4.).collect();
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Variable line width and style", &[])
+		.set_cb_label("Speed", &[])
+		.lines(
+			&x,
+			&y,
+			&[
+				VariableLineWidth(speed.clone()),
+				VariableLineStyle(
+					speed
+						.iter()
+						.map(|&s| {
+							if s < 2.
+							{
+								Dash
+							}
+							else
+							{
+								Solid
+							}
+						})
+						.collect(),
+				),
+				Color(VariablePaletteColor(speed.clone())),
+			],
+		);
+
+	c.show(&mut fg, "dash_type_variable");
 }
 
 fn main()
@@ -77,6 +77,19 @@ fn example(c: Common)
 		.set_view(45.0, 45.0);
 
 	c.show(&mut fg, "example3_3");
+
+	let mut fg = Figure::new();
+
+	fg.axes3d()
+		.set_title("Equal axes fg3.4", &[])
+		.surface(z1.iter(), w, h, Some((-4.0, -4.0, 4.0, 4.0)), &[])
+		.set_view(60.0, 30.0)
+		.set_view_scale(1.2, 2.0)
+		.set_view_equal(Some(EqualXY))
+		.set_view_azimuth(10.0)
+		.set_xy_plane(XYPlaneRelative(0.0));
+
+	c.show(&mut fg, "example3_4");
 }
 
 fn main()
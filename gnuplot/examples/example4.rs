@@ -114,6 +114,53 @@ fn example(c: Common)
 		.set_view(45.0, 45.0);
 
 	c.show(&mut fg, "example4_5");
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Color box fg4.6", &[])
+		.set_cb_range(Fix(-1.0), Fix(1.0))
+		.set_cb_ticks(Some((Fix(0.5), 1)), &[Inward(false)], &[])
+		.set_cb_box(true, &[ColorBoxHorizontal, ColorBoxInside])
+		.image(z1.iter(), zw, zh, Some((-4.0, -4.0, 4.0, 4.0)), &[]);
+
+	c.show(&mut fg, "example4_6");
+
+	let classes: Vec<f64> = z1
+		.iter()
+		.map(|&z| ((z + 1.0) * 2.0).floor().clamp(0.0, 3.0))
+		.collect();
+	let colors: [ColorType; 4] = [
+		"navy".into(),
+		"#4477aa".into(),
+		"gold".into(),
+		"dark-red".into(),
+	];
+	let palette = palettes::stepped_palette(&colors);
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Stepped palette fg4.7", &[])
+		.set_palette(Custom(&palette))
+		.set_palette_max_colors(Some(colors.len() as u32))
+		.set_cb_range(Fix(-0.5), Fix(colors.len() as f64 - 0.5))
+		.set_cb_ticks(Some((Fix(1.0), 0)), &[], &[])
+		.image(classes.iter(), zw, zh, Some((-4.0, -4.0, 4.0, 4.0)), &[]);
+
+	c.show(&mut fg, "example4_7");
+
+	let palette = palettes::by_name("RdBu_r").unwrap();
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Palette by name fg4.8", &[])
+		.set_palette(palette.to_ref())
+		.set_cb_range(Fix(-1.0), Fix(1.0))
+		.image(z1.iter(), zw, zh, Some((-4.0, -4.0, 4.0, 4.0)), &[]);
+
+	c.show(&mut fg, "example4_8");
 }
 
 fn main()
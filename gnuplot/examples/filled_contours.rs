@@ -0,0 +1,44 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let w = 61;
+	let h = 61;
+	let mut z = Vec::with_capacity(w * h);
+	for i in 0..h
+	{
+		for j in 0..w
+		{
+			let y = 8.0 * (i as f64) / h as f64 - 4.0;
+			let x = 8.0 * (j as f64) / w as f64 - 4.0;
+			z.push(x.cos() * y.cos() / ((x * x + y * y).sqrt() + 1.0));
+		}
+	}
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Filled contours", &[])
+		.set_palette(HELIX)
+		.filled_contours(
+			z.iter(),
+			h,
+			w,
+			Some((-4.0, -4.0, 4.0, 4.0)),
+			[-0.8, -0.4, -0.2, 0.0, 0.2, 0.4, 0.8],
+			&[BorderColor("black".into()), LineWidth(1.5)],
+		)
+		.set_x_label("X", &[])
+		.set_y_label("Y", &[]);
+
+	c.show(&mut fg, "filled_contours");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
@@ -0,0 +1,33 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let x: Vec<f64> = (0..1000).map(|i| i as f64 / 100.0).collect();
+	let y: Vec<f64> = x
+		.iter()
+		.map(|&x| x.sin() + 0.05 * (20.0 * x).sin())
+		.collect();
+
+	let mut fg = Figure::new();
+
+	let ax = fg.axes2d();
+	ax.set_title("Inset", &[])
+		.lines(&x, &y, &[Caption("Signal"), Color("black".into())])
+		.set_y_range(Fix(-1.5), Fix(2.5));
+	ax.inset(0.55, 0.6, 0.4, 0.35)
+		.lines(&x, &y, &[Color("black".into())])
+		.set_x_range(Fix(1.2), Fix(2.0))
+		.set_y_range(Fix(0.8), Fix(1.1));
+	ax.mark_inset(0, &[Color("gray".into())]);
+
+	c.show(&mut fg, "inset_1");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
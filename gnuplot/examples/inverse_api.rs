@@ -29,12 +29,18 @@ impl PlotElement for Lines
 			options.push(match o
 			{
 				PointSymbol(v) => PointSymbol(*v),
+				PointGlyph(v) => PointGlyph(*v),
 				PointSize(v) => PointSize(*v),
+				VariablePointSymbol(v) => VariablePointSymbol(v.to_vec()),
+				VariablePointSize(v) => VariablePointSize(v.to_vec()),
+				VariablePointArea(v) => VariablePointArea(v.to_vec()),
 				Caption(v) => Caption(&v),
 				LineWidth(v) => LineWidth(*v),
 				Color(v) => Color(v.to_ref()),
 				BorderColor(v) => BorderColor(v.to_ref()),
-				LineStyle(v) => LineStyle(*v),
+				LineStyle(v) => LineStyle(v.clone()),
+				VariableLineWidth(v) => VariableLineWidth(v.to_vec()),
+				VariableLineStyle(v) => VariableLineStyle(v.to_vec()),
 				FillAlpha(v) => FillAlpha(*v),
 				FillRegion(v) => FillRegion(*v),
 				ArrowType(v) => ArrowType(*v),
@@ -43,6 +49,9 @@ impl PlotElement for Lines
 				FillPattern(v) => FillPattern(*v),
 				Axes(v1, v2) => Axes(*v1, *v2),
 				BoxWidth(v) => BoxWidth(v.to_vec()),
+				Layer(v) => Layer(*v),
+				Clip(v) => Clip(*v),
+				ZOrder(v) => ZOrder(*v),
 			});
 		}
 
@@ -13,13 +13,46 @@ fn example(c: Common)
 	let mut fg = Figure::new();
 
 	fg.axes3d().set_title("3D lines", &[]).lines(
-		x,
-		y,
-		z,
+		x.clone(),
+		y.clone(),
+		z.clone(),
 		&[PointSymbol('o'), Color("#ffaa77".into()), PointSize(2.0)],
 	);
 
 	c.show(&mut fg, "lines_3d");
+
+	let mut fg = Figure::new();
+
+	fg.axes3d()
+		.set_title("3D lines with arrows, legend and border", &[])
+		.lines(
+			x,
+			y,
+			z,
+			&[Caption("Helix"), Color("#ffaa77".into()), LineWidth(2.0)],
+		)
+		.arrow(
+			Coordinate3D::new(Axis(0.0), Axis(0.0), Axis(0.0)),
+			Coordinate3D::new(Axis(0.0), Axis(0.0), Axis(10.0)),
+			&[ArrowType(Filled), Color("black".into())],
+		)
+		.set_legend(Graph(0.9), Graph(0.9), &[], &[])
+		.set_border(
+			false,
+			&[
+				BottomLeftFront,
+				BottomLeftBack,
+				BottomRightFront,
+				BottomRightBack,
+				LeftVertical,
+				BackVertical,
+				RightVertical,
+				FrontVertical,
+			],
+			&[Color("gray".into())],
+		);
+
+	c.show(&mut fg, "lines_3d_2");
 }
 
 fn main()
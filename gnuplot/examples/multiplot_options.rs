@@ -40,6 +40,53 @@ fn example(c: Common)
 	);
 
 	c.show(&mut fg, "multiplot_options");
+
+	let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+	let mut fg = Figure::new();
+	fg.set_multiplot_layout(3, 1);
+
+	fg.axes2d()
+		.lines(&x, x.iter().map(|x| x.sin()), &[Caption("sin")])
+		.set_y_label("Short", &[]);
+	fg.axes2d()
+		.lines(
+			&x[..50],
+			x.iter().map(|x| 100.0 * x.cos()),
+			&[Caption("cos")],
+		)
+		.set_y_label("Long tick labels", &[]);
+	fg.axes2d()
+		.lines(
+			&x[20..],
+			x[20..].iter().map(|x| x.sqrt()),
+			&[Caption("sqrt")],
+		)
+		.set_x_label("Shared X axis", &[]);
+
+	fg.share_x_axes(&[0, 1, 2]);
+
+	c.show(&mut fg, "multiplot_options_2");
+
+	let mut gs = GridSpec::new(2, 3);
+	gs.set_width_ratios(&[2.0, 1.0, 1.0])
+		.set_height_ratios(&[1.0, 2.0])
+		.set_spacing(0.08, 0.1);
+
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_grid_spec_cell(&gs, 0..1, 0..3)
+		.set_title("Wide", &[])
+		.lines(&x, x.iter().map(|x| x.sin()), &[]);
+	fg.axes2d()
+		.set_grid_spec_cell(&gs, 1..2, 0..1)
+		.set_title("Large", &[])
+		.points(&x, x.iter().map(|x| x.cos()), &[]);
+	fg.axes2d()
+		.set_grid_spec_cell(&gs, 1..2, 1..3)
+		.set_title("Medium", &[])
+		.boxes(0..5, [1, 3, 2, 5, 4], &[]);
+
+	c.show(&mut fg, "multiplot_options_3");
 }
 
 fn main()
@@ -0,0 +1,121 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let x: Vec<f64> = (0..200).map(|i| i as f64 / 10.0).collect();
+	let y: Vec<f64> = x.iter().map(|&x| (x / 2.0).sin() * x).collect();
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Shapes", &[])
+		.rectangle(
+			Axis(5.0),
+			Graph(0.0),
+			Axis(9.0),
+			Graph(1.0),
+			&[Color("gray".into()), FillAlpha(0.3), Layer(Behind)],
+		)
+		.circle(
+			Axis(15.0),
+			Axis(-10.0),
+			Axis(1.5),
+			&[
+				Color("red".into()),
+				FillAlpha(0.0),
+				BorderColor("red".into()),
+				LineWidth(2.0),
+			],
+		)
+		.ellipse(
+			Graph(0.2),
+			Graph(0.8),
+			Graph(0.2),
+			Graph(0.1),
+			30.0,
+			&[Color("#4477aa".into()), FillAlpha(0.5), Layer(Front)],
+		)
+		.polygon_object(
+			&[
+				(Axis(12.0), Axis(12.0)),
+				(Axis(16.0), Axis(12.0)),
+				(Axis(14.0), Axis(16.0)),
+			],
+			&[
+				FillPattern(Fix(BigCrosses)),
+				Color("dark-green".into()),
+				BorderColor("black".into()),
+				LineStyle(Dash),
+				Clip(false),
+			],
+		)
+		.lines(&x, &y, &[Color("black".into())]);
+
+	c.show(&mut fg, "shapes");
+
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Reference lines and spans", &[])
+		.axvspan(
+			5.0,
+			9.0,
+			&[
+				Caption("Window"),
+				Color("gray".into()),
+				FillAlpha(0.3),
+				Layer(Behind),
+			],
+		)
+		.axhspan(-5.0, 5.0, &[Color("#88ccee".into()), FillAlpha(0.3)])
+		.axhline(
+			10.0,
+			&[Caption("Threshold"), Color("red".into()), LineStyle(Dash)],
+		)
+		.axvline(15.0, &[Color("blue".into()), LineWidth(2.0)])
+		.lines(&x, &y, &[Color("black".into())]);
+
+	c.show(&mut fg, "shapes_2");
+
+	// The grid and the shaded region stay behind the data. The thick line is added first, but is
+	// drawn on top thanks to its z-order, while the label and the arrow are on the front layer.
+	let mut fg = Figure::new();
+
+	fg.axes2d()
+		.set_title("Layers and z-order", &[])
+		.set_grid_options(false, &[Color("gray".into())])
+		.set_grid_layer(Back)
+		.set_x_grid(true)
+		.set_y_grid(true)
+		.axvspan(5.0, 9.0, &[Color("#88ccee".into()), Layer(Back)])
+		.lines(
+			&x,
+			&y,
+			&[Color("#ee7733".into()), LineWidth(4.0), ZOrder(1)],
+		)
+		.lines(&x, y.iter().map(|y| -y), &[Color("black".into())])
+		.label(
+			"On top",
+			Axis(7.0),
+			Axis(0.0),
+			&[LabelLayer(Front), LabelZOrder(1)],
+		)
+		.arrow(
+			Axis(2.0),
+			Axis(-10.0),
+			Axis(7.0),
+			Axis(-1.0),
+			&[Layer(Front), ZOrder(1)],
+		);
+
+	c.show(&mut fg, "shapes_3");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
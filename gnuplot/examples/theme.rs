@@ -0,0 +1,62 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+	let y1: Vec<f64> = x.iter().map(|&x| x.sin()).collect();
+	let y2: Vec<f64> = x.iter().map(|&x| x.cos()).collect();
+
+	let mut fg = Figure::new();
+	fg.set_theme(&Theme::dark());
+
+	fg.axes2d()
+		.set_title("Dark theme", &[])
+		.set_x_label("X", &[])
+		.set_y_label("Y", &[])
+		.lines(&x, &y1, &[Caption("sin(x)")])
+		.lines(&x, &y2, &[Caption("cos(x)")]);
+
+	c.show(&mut fg, "theme_1");
+
+	#[cfg(feature = "toml")]
+	theme_from_toml(&c, &x, &y1, &y2);
+}
+
+#[cfg(feature = "toml")]
+fn theme_from_toml(c: &Common, x: &[f64], y1: &[f64], y2: &[f64])
+{
+	let theme = Theme::from_toml(
+		r##"
+		base = "publication"
+
+		[font]
+		size = 12
+
+		[grid]
+		show = true
+		color = "#e0e0e0"
+		"##,
+	)
+	.unwrap();
+
+	let mut fg = Figure::new();
+	fg.set_theme(&theme);
+
+	fg.axes2d()
+		.set_title("Theme loaded from TOML", &[])
+		.set_x_label("X", &[])
+		.set_y_label("Y", &[])
+		.lines(x, y1, &[Caption("sin(x)")])
+		.lines(x, y2, &[Caption("cos(x)")]);
+
+	c.show(&mut fg, "theme_2");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
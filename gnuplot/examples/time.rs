@@ -39,6 +39,30 @@ fn example(c: Common)
 		.set_x_time(true);
 
 	c.show(&mut fg, "time_2");
+
+	// 2024-01-01 00:00:00 UTC
+	let start = 1704067200.0;
+	let day = 3600.0 * 24.0;
+	let x3: Vec<f64> = (0..90).map(|i| start + i as f64 * day).collect();
+	let y3: Vec<f64> = (0..90).map(|i| (i as f64 / 7.0).sin()).collect();
+
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_title("Time 3: Automatic time ticks", &[])
+		.lines(&x3, &y3, &[])
+		.set_x_range(Fix(start), Fix(start + 89.0 * day))
+		.set_x_time_ticks(AutoTimeTicks, None, &[], &[]);
+
+	c.show(&mut fg, "time_3");
+
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_title("Time 4: Business days", &[])
+		.lines(&x3[..14], &y3[..14], &[])
+		.set_x_range(Fix(start), Fix(start + 13.0 * day))
+		.set_x_time_ticks(BusinessDayTicks, Some("%a\\n%d"), &[], &[]);
+
+	c.show(&mut fg, "time_4");
 }
 
 fn main()
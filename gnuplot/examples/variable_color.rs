@@ -206,6 +206,69 @@ fn example(c: Common)
 	);
 
 	c.show(&mut fg, "variable_palette");
+
+	// Bubble chart: the area of each point is proportional to the value in the last column, and the
+	// symbol alternates between circles and squares.
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_title("Variable point size and symbol", &[])
+		.set_x_range(Fix(0.0), Fix(11.0))
+		.set_y_range(Fix(0.0), Fix(10.0))
+		.points(
+			&d1,
+			&d5,
+			&[
+				Caption("Area proportional to column 6"),
+				VariablePointArea(d6.clone()),
+				VariablePointSymbol(
+					d1.iter()
+						.map(|&x| {
+							if x < 5.5
+							{
+								'O'
+							}
+							else
+							{
+								'S'
+							}
+						})
+						.collect(),
+				),
+				Color(VariablePaletteColor(d6.clone())),
+			],
+		);
+
+	c.show(&mut fg, "variable_point_size");
+
+	// Glyphs can be used as point markers, and filled symbols can be outlined with `BorderColor`.
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_title("Point glyphs and outlines", &[])
+		.set_x_range(Fix(0.0), Fix(11.0))
+		.set_y_range(Fix(0.0), Fix(10.0))
+		.points(
+			&d1,
+			&d2,
+			&[
+				Caption("Glyph"),
+				PointGlyph('★'),
+				PointSize(2.0),
+				Color("#ee7733".into()),
+			],
+		)
+		.points(
+			&d1,
+			&d6,
+			&[
+				Caption("Outlined circles"),
+				PointSymbol('O'),
+				PointSize(2.0),
+				Color("#88ccee".into()),
+				BorderColor("black".into()),
+			],
+		);
+
+	c.show(&mut fg, "point_markers");
 }
 
 fn main()
@@ -0,0 +1,56 @@
+// This file is released into Public Domain.
+use crate::common::*;
+use gnuplot::*;
+
+mod common;
+
+fn example(c: Common)
+{
+	let n = 20;
+	let mut grid = Vec::with_capacity(n * n * n);
+	for k in 0..n
+	{
+		for j in 0..n
+		{
+			for i in 0..n
+			{
+				let [x, y, z] = [i, j, k].map(|v| 4.0 * v as f64 / (n - 1) as f64 - 2.0);
+				grid.push((-(x * x + y * y + 2.0 * z * z)).exp());
+			}
+		}
+	}
+	let bounds = (-2.0, -2.0, -2.0, 2.0, 2.0, 2.0);
+
+	let mut fg = Figure::new();
+
+	fg.axes3d()
+		.set_pos_grid(1, 2, 0)
+		.set_title("Isosurfaces", &[])
+		.voxels(
+			grid.iter(),
+			(n, n, n),
+			bounds,
+			Isosurfaces(vec![0.2, 0.6]),
+			&[FillAlpha(0.5), Color("#4488cc".into())],
+		)
+		.set_view_equal(Some(EqualXYZ));
+
+	fg.axes3d()
+		.set_pos_grid(1, 2, 1)
+		.set_title("Voxel points", &[])
+		.voxels(
+			grid.iter(),
+			(n, n, n),
+			bounds,
+			VoxelPoints(0.1),
+			&[PointSymbol('O'), PointSize(0.5)],
+		)
+		.set_view_equal(Some(EqualXYZ));
+
+	c.show(&mut fg, "voxels");
+}
+
+fn main()
+{
+	Common::new().map(|c| example(c));
+}
@@ -0,0 +1,5807 @@
+
+set encoding utf8
+set terminal gif animate optimize delay 2 size 480,360
+set output "gif.gif"
+set termoption dashed
+set termoption enhanced
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/0/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/0/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/2/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/2/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/4/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/4/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/6/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/6/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/8/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/8/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/10/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/10/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/12/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/12/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/14/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/14/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/16/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/16/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/18/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/18/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/20/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/20/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/22/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/22/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/24/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/24/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/26/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/26/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/28/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/28/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/30/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/30/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/32/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/32/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/34/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/34/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/36/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/36/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/38/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/38/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/40/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/40/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/42/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/42/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/44/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/44/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/46/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/46/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/48/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/48/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/50/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/50/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/52/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/52/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/54/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/54/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/56/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/56/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/58/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/58/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/60/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/60/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/62/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/62/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/64/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/64/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/66/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/66/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/68/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/68/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/70/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/70/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/72/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/72/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/74/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/74/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/76/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/76/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/78/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/78/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/80/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/80/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/82/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/82/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/84/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/84/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/86/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/86/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/88/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/88/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/90/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/90/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/92/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/92/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/94/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/94/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/96/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/96/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/98/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/98/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/100/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/100/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/102/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/102/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/104/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/104/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/106/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/106/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/108/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/108/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/110/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/110/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/112/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/112/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/114/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/114/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/116/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/116/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/118/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/118/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/120/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/120/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/122/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/122/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/124/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/124/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/126/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/126/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/128/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/128/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/130/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/130/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/132/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/132/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/134/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/134/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/136/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/136/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/138/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/138/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/140/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/140/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/142/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/142/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/144/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/144/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/146/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/146/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/148/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/148/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/150/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/150/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/152/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/152/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/154/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/154/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/156/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/156/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/158/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/158/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/160/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/160/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/162/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/162/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/164/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/164/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/166/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/166/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/168/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/168/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/170/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/170/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/172/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/172/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/174/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/174/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/176/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/176/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/178/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/178/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/180/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/180/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/182/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/182/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/184/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/184/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/186/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/186/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/188/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/188/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/190/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/190/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/192/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/192/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/194/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/194/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/196/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/196/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+set multiplot
+set origin
+set size
+set size noratio
+set lmargin
+set rmargin
+set tmargin
+set bmargin
+set palette rgbformulae 7,5,15
+unset xzeroaxis 
+unset logscale x
+set xdata
+set mxtics 1
+
+set xrange [*:*]
+set xtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set xlabel ""
+
+unset yzeroaxis 
+unset logscale y
+set ydata
+set mytics 1
+
+set yrange [-1.000000000000e0:1.000000000000e0]
+set ytics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set ylabel ""
+
+unset x2zeroaxis 
+unset logscale x2
+set x2data
+unset mx2tics
+
+set x2range [*:*]
+unset x2tics
+set x2label ""
+
+unset y2zeroaxis 
+unset logscale y2
+set y2data
+unset my2tics
+
+set y2range [*:*]
+unset y2tics
+set y2label ""
+
+
+unset logscale cb
+set cbdata
+set mcbtics 1
+
+set cbrange [*:*]
+set cbtics autofreq scale 5.000000000000e-1,5.000000000000e-1
+set cblabel ""
+
+set title ""
+set border 15 front  linecolor black lw 1
+plot "/tmp/.tmp0eHI0X/198/0.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "blue" t "", "/tmp/.tmp0eHI0X/198/1.bin" binary endian=little record=100 format="%float64" using 1:2 with lines lw 1 linecolor rgb "red" t ""
+unset multiplot
+
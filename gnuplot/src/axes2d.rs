@@ -3,251 +3,26 @@
 // All rights reserved. Distributed under LGPL 3.0. For full terms see the file LICENSE.
 
 use std::iter;
+use std::path;
 
 use crate::axes_common::*;
 use crate::coordinates::*;
 use crate::datatype::*;
 use crate::options::*;
+use crate::style::Theme;
 use crate::util::{escape, OneWayOwned};
 use crate::writer::Writer;
-use crate::ColorType;
-
-struct LegendData
-{
-	x: Coordinate,
-	y: Coordinate,
-	legend_options: Vec<LegendOption<String>>,
-	text_options: Vec<LabelOption<String>>,
-}
-
-impl LegendData
-{
-	fn write_out(&self, writer: &mut dyn Writer)
-	{
-		let w = writer;
-		write!(w, "set key at {},{}", self.x, self.y);
-
-		first_opt_default! {self.legend_options,
-			Placement(h, v) =>
-			{
-				w.write_str(match h
-				{
-					AlignLeft => " left",
-					AlignRight => " right",
-					_ => " center"
-				});
-				w.write_str(match v
-				{
-					AlignTop => " top",
-					AlignBottom => " bottom",
-					_ => " center"
-				});
-			},
-			_ =>
-			{
-				w.write_str(" right top");
-			}
-		}
-
-		first_opt_default! {self.legend_options,
-			Horizontal =>
-			{
-				w.write_str(" horizontal");
-			},
-			_ =>
-			{
-				w.write_str(" vertical");
-			}
-		}
-
-		first_opt_default! {self.legend_options,
-			Reverse =>
-			{
-				w.write_str(" reverse");
-			},
-			_ =>
-			{
-				w.write_str(" noreverse");
-			}
-		}
-
-		first_opt_default! {self.legend_options,
-			Invert =>
-			{
-				w.write_str(" invert");
-			},
-			_ =>
-			{
-				w.write_str(" noinvert");
-			}
-		}
-
-		first_opt! {self.legend_options,
-			Title(ref s) =>
-			{
-				w.write_str(" title \"");
-				w.write_str(&escape(s));
-				w.write_str("\"");
-			}
-		}
-
-		first_opt! {self.text_options,
-			Font(ref f, s) =>
-			{
-				w.write_str(" font \"");
-				w.write_str(&escape(f));
-				w.write_str(",");
-				w.write_str(&s.to_string()[..]);
-				w.write_str("\"");
-			}
-		}
-		first_opt! {self.text_options,
-			TextColor(ref s) =>
-			{
-				write!(w, " textcolor {} ", s.command());
-			}
-		}
-		first_opt! {self.text_options,
-			TextAlign(a) =>
-			{
-				w.write_str(match a
-				{
-					AlignLeft => " Left",
-					AlignRight => " Right",
-					_ => ""
-				});
-			}
-		}
-
-		first_opt! {self.legend_options,
-			MaxRows(r) =>
-			{
-				write!(w, " maxrows {}", r as i32);
-			}
-		}
-
-		first_opt! {self.legend_options,
-			MaxCols(l) =>
-			{
-				write!(w, " maxcols {}", l as i32);
-			}
-		}
-
-		w.write_str("\n");
-	}
-
-	fn reset_state(&self, writer: &mut dyn Writer)
-	{
-		writer.write_str("unset key\n");
-	}
-}
-
-struct ArrowData
-{
-	x1: Coordinate,
-	y1: Coordinate,
-	x2: Coordinate,
-	y2: Coordinate,
-	plot_options: Vec<PlotOption<String>>,
-	tag: i32,
-}
-
-impl ArrowData
-{
-	fn write_out(&self, writer: &mut dyn Writer)
-	{
-		let w = writer;
-		write!(
-			w,
-			"set arrow {} from {},{} to {},{}",
-			self.tag, self.x1, self.y1, self.x2, self.y2
-		);
-
-		first_opt! {self.plot_options,
-			ArrowType(s) =>
-			{
-				w.write_str(match s
-				{
-					Open => "",
-					Closed => " empty",
-					Filled => " filled",
-					NoArrow => " nohead",
-				});
-			}
-		}
-
-		w.write_str(" size graph ");
-		first_opt_default! {self.plot_options,
-			ArrowSize(z) =>
-			{
-				write!(w, "{:.12e}", z);
-			},
-			_ =>
-			{
-				w.write_str("0.05");
-			}
-		}
-		w.write_str(",12");
-
-		AxesCommonData::write_color_options(w, &self.plot_options, false, Some(ColorType::Black));
-		AxesCommonData::write_line_options(
-			w,
-			&self.plot_options,
-			GnuplotVersion { major: 0, minor: 0 },
-		);
-
-		w.write_str("\n");
-	}
-
-	fn reset_state(&self, writer: &mut dyn Writer)
-	{
-		writeln!(writer, "unset arrow {}", self.tag);
-	}
-}
-
-struct BorderOptions
-{
-	front: bool,
-	locations: Vec<BorderLocation2D>,
-	options: Vec<PlotOption<String>>,
-}
-
-impl BorderOptions
-{
-	fn new() -> BorderOptions
-	{
-		BorderOptions {
-			front: true,
-			locations: vec![Bottom, Left, Top, Right],
-			options: vec![],
-		}
-	}
-
-	fn write_out(&self, writer: &mut dyn Writer, version: GnuplotVersion)
-	{
-		writer.write_str("set border ");
-		let mut f: i32 = 0;
-		for &l in self.locations.iter()
-		{
-			f |= l as i32;
-		}
-		write!(writer, "{}", f);
-		writer.write_str(if self.front { " front " } else { " back " });
-
-		AxesCommonData::write_color_options(writer, &self.options, false, Some(ColorType::Black));
-		AxesCommonData::write_line_options(writer, &self.options, version);
-
-		writer.write_str("\n");
-	}
-}
 
 /// 2D axes that is used for drawing 2D plots
 pub struct Axes2D
 {
 	common: AxesCommonData,
-	border_options: BorderOptions,
+	border_options: Option<BorderOptions>,
 	arrows: Vec<ArrowData>,
+	objects: Vec<ObjectData>,
 	legend: Option<LegendData>,
+	insets: Vec<Axes2D>,
+	inset_area: Option<(f64, f64, f64, f64)>,
 }
 
 impl Axes2D
@@ -256,9 +31,12 @@ impl Axes2D
 	{
 		Axes2D {
 			common: AxesCommonData::new(),
-			border_options: BorderOptions::new(),
+			border_options: None,
 			arrows: vec![],
+			objects: vec![],
 			legend: None,
+			insets: vec![],
+			inset_area: None,
 		}
 	}
 
@@ -276,9 +54,10 @@ impl Axes2D
 		&'l mut self, front: bool, locations: &[BorderLocation2D], options: &[PlotOption<&str>],
 	) -> &'l mut Self
 	{
-		self.border_options.front = front;
-		self.border_options.locations = locations.into();
-		self.border_options.options = options.to_one_way_owned();
+		let mut border_options = BorderOptions::new(locations.iter().fold(0, |f, &l| f | l as i32));
+		border_options.front = front;
+		border_options.options = options.to_one_way_owned();
+		self.border_options = Some(border_options);
 		self
 	}
 
@@ -318,22 +97,247 @@ impl Axes2D
 	///      * `Color` - Specifies the color of the arrow
 	///      * `LineStyle` - Specifies the style of the arrow shaft
 	///      * `LineWidth` - Specifies the width of the arrow shaft
+	///      * `Layer` - Specifies the layer the arrow is drawn on (`Behind` is treated as `Back`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other arrows
 	pub fn arrow<'l>(
 		&'l mut self, x1: Coordinate, y1: Coordinate, x2: Coordinate, y2: Coordinate,
 		options: &[PlotOption<&str>],
 	) -> &'l mut Self
 	{
 		self.arrows.push(ArrowData {
-			x1,
-			y1,
-			x2,
-			y2,
-			tag: self.arrows.len() as i32 + 1,
+			from: vec![x1, y1],
+			to: vec![x2, y2],
 			plot_options: options.to_one_way_owned(),
 		});
 		self
 	}
 
+	fn object<'l>(&'l mut self, shape: ObjectShape, options: &[PlotOption<&str>]) -> &'l mut Self
+	{
+		self.objects.push(ObjectData {
+			shape,
+			plot_options: options.to_one_way_owned(),
+		});
+		self
+	}
+
+	/// Adds a rectangle to the plot, spanning from `(x1, y1)` to `(x2, y2)`.
+	/// # Arguments
+	/// * `x1` - X coordinate of the first corner
+	/// * `y1` - Y coordinate of the first corner
+	/// * `x2` - X coordinate of the opposite corner
+	/// * `y2` - Y coordinate of the opposite corner
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the rectangle. Relevant options are:
+	///      * `Color` - Specifies the fill color. Without it (or `FillPattern`), the shape is not filled.
+	///      * `FillAlpha` - Sets the transparency of the fill (default is opaque)
+	///      * `FillPattern` - Fills with a pattern instead of a solid color
+	///      * `BorderColor` - Draws a border of this color. By default, only shapes that are not filled
+	///        get a border.
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	///      * `Layer` - Specifies the layer the shape is drawn on (default is `Back`)
+	///      * `Clip` - Whether or not to clip the shape to the plot area (default is `true`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other shapes on the same layer
+	pub fn rectangle<'l>(
+		&'l mut self, x1: Coordinate, y1: Coordinate, x2: Coordinate, y2: Coordinate,
+		options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.object(ObjectShape::Rectangle(x1, y1, x2, y2), options)
+	}
+
+	/// Adds a circle to the plot.
+	/// # Arguments
+	/// * `x` - X coordinate of the center
+	/// * `y` - Y coordinate of the center
+	/// * `radius` - Radius of the circle. `Axis` radii are measured along the X axis.
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the circle. Relevant options are:
+	///      * `Color` - Specifies the fill color. Without it (or `FillPattern`), the shape is not filled.
+	///      * `FillAlpha` - Sets the transparency of the fill (default is opaque)
+	///      * `FillPattern` - Fills with a pattern instead of a solid color
+	///      * `BorderColor` - Draws a border of this color. By default, only shapes that are not filled
+	///        get a border.
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	///      * `Layer` - Specifies the layer the shape is drawn on (default is `Back`)
+	///      * `Clip` - Whether or not to clip the shape to the plot area (default is `true`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other shapes on the same layer
+	pub fn circle<'l>(
+		&'l mut self, x: Coordinate, y: Coordinate, radius: Coordinate,
+		options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.object(ObjectShape::Circle(x, y, radius), options)
+	}
+
+	/// Adds an ellipse to the plot.
+	/// # Arguments
+	/// * `x` - X coordinate of the center
+	/// * `y` - Y coordinate of the center
+	/// * `width` - Length of the first axis of the ellipse
+	/// * `height` - Length of the second axis of the ellipse
+	/// * `angle` - Angle between the first axis of the ellipse and the X axis, in degrees
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the ellipse. Relevant options are:
+	///      * `Color` - Specifies the fill color. Without it (or `FillPattern`), the shape is not filled.
+	///      * `FillAlpha` - Sets the transparency of the fill (default is opaque)
+	///      * `FillPattern` - Fills with a pattern instead of a solid color
+	///      * `BorderColor` - Draws a border of this color. By default, only shapes that are not filled
+	///        get a border.
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	///      * `Layer` - Specifies the layer the shape is drawn on (default is `Back`)
+	///      * `Clip` - Whether or not to clip the shape to the plot area (default is `true`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other shapes on the same layer
+	pub fn ellipse<'l>(
+		&'l mut self, x: Coordinate, y: Coordinate, width: Coordinate, height: Coordinate,
+		angle: f64, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.object(ObjectShape::Ellipse(x, y, width, height, angle), options)
+	}
+
+	/// Adds a polygon to the plot. Unlike `polygon`, the vertices can use any `Coordinate` and the
+	/// polygon is not a plot element (e.g. it does not affect the axis ranges or appear in the legend).
+	/// # Arguments
+	/// * `vertices` - Coordinates of the vertices, must not be empty. The polygon is closed automatically.
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the polygon. Relevant options are:
+	///      * `Color` - Specifies the fill color. Without it (or `FillPattern`), the shape is not filled.
+	///      * `FillAlpha` - Sets the transparency of the fill (default is opaque)
+	///      * `FillPattern` - Fills with a pattern instead of a solid color
+	///      * `BorderColor` - Draws a border of this color. By default, only shapes that are not filled
+	///        get a border.
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	///      * `Layer` - Specifies the layer the shape is drawn on (default is `Back`)
+	///      * `Clip` - Whether or not to clip the shape to the plot area (default is `true`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other shapes on the same layer
+	pub fn polygon_object<'l>(
+		&'l mut self, vertices: &[(Coordinate, Coordinate)], options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		assert!(
+			!vertices.is_empty(),
+			"Cannot add a polygon without vertices"
+		);
+		self.object(ObjectShape::Polygon(vertices.to_vec()), options)
+	}
+
+	fn reference_label(
+		&mut self, x: Coordinate, y: Coordinate, align: AlignType, offset: (f64, f64),
+		options: &[PlotOption<&str>],
+	)
+	{
+		let mut label_options = vec![TextAlign(align), TextOffset(offset.0, offset.1)];
+		first_opt! {options,
+			Color(ref c) =>
+			{
+				label_options.push(TextColor(c.clone()));
+			}
+		}
+		first_opt! {options,
+			Caption(text) =>
+			{
+				self.label(text, x, y, &label_options);
+			}
+		}
+	}
+
+	/// Adds a horizontal line spanning the whole width of the plot.
+	/// # Arguments
+	/// * `y` - Y coordinate of the line, in axis units
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the line. Relevant options are:
+	///      * `Caption` - Text of a label drawn above the right end of the line. Omit to draw no label.
+	///      * `Color` - Specifies the color of the line and its label
+	///      * `LineStyle` - Specifies the style of the line
+	///      * `LineWidth` - Specifies the width of the line
+	///      * `Axes` - Pass `Y2` to anchor the line to the secondary Y axis
+	pub fn axhline<'l>(&'l mut self, y: f64, options: &[PlotOption<&str>]) -> &'l mut Self
+	{
+		let (_, y_coord) = reference_coordinates(options);
+		let mut line_options = vec![ArrowType(NoArrow)];
+		line_options.extend_from_slice(options);
+		self.arrow(
+			Graph(0.0),
+			y_coord(y),
+			Graph(1.0),
+			y_coord(y),
+			&line_options,
+		);
+		self.reference_label(Graph(1.0), y_coord(y), AlignRight, (-0.5, 0.5), options);
+		self
+	}
+
+	/// Adds a vertical line spanning the whole height of the plot.
+	/// # Arguments
+	/// * `x` - X coordinate of the line, in axis units
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the line. Relevant options are:
+	///      * `Caption` - Text of a label drawn next to the top end of the line. Omit to draw no label.
+	///      * `Color` - Specifies the color of the line and its label
+	///      * `LineStyle` - Specifies the style of the line
+	///      * `LineWidth` - Specifies the width of the line
+	///      * `Axes` - Pass `X2` to anchor the line to the secondary X axis
+	pub fn axvline<'l>(&'l mut self, x: f64, options: &[PlotOption<&str>]) -> &'l mut Self
+	{
+		let (x_coord, _) = reference_coordinates(options);
+		let mut line_options = vec![ArrowType(NoArrow)];
+		line_options.extend_from_slice(options);
+		self.arrow(
+			x_coord(x),
+			Graph(0.0),
+			x_coord(x),
+			Graph(1.0),
+			&line_options,
+		);
+		self.reference_label(x_coord(x), Graph(1.0), AlignLeft, (0.5, -1.0), options);
+		self
+	}
+
+	/// Adds a horizontal band spanning the whole width of the plot.
+	/// # Arguments
+	/// * `y0` - Y coordinate of the bottom of the band, in axis units
+	/// * `y1` - Y coordinate of the top of the band, in axis units
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the band. Relevant options are
+	///   the same as for `rectangle`, as well as:
+	///      * `Caption` - Text of a label drawn inside the right end of the band. Omit to draw no label.
+	///      * `Axes` - Pass `Y2` to anchor the band to the secondary Y axis
+	pub fn axhspan<'l>(&'l mut self, y0: f64, y1: f64, options: &[PlotOption<&str>])
+		-> &'l mut Self
+	{
+		let (_, y_coord) = reference_coordinates(options);
+		self.rectangle(Graph(0.0), y_coord(y0), Graph(1.0), y_coord(y1), options);
+		self.reference_label(
+			Graph(1.0),
+			y_coord((y0 + y1) / 2.0),
+			AlignRight,
+			(-0.5, 0.0),
+			options,
+		);
+		self
+	}
+
+	/// Adds a vertical band spanning the whole height of the plot.
+	/// # Arguments
+	/// * `x0` - X coordinate of the left side of the band, in axis units
+	/// * `x1` - X coordinate of the right side of the band, in axis units
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the band. Relevant options are
+	///   the same as for `rectangle`, as well as:
+	///      * `Caption` - Text of a label drawn inside the top of the band. Omit to draw no label.
+	///      * `Axes` - Pass `X2` to anchor the band to the secondary X axis
+	pub fn axvspan<'l>(&'l mut self, x0: f64, x1: f64, options: &[PlotOption<&str>])
+		-> &'l mut Self
+	{
+		let (x_coord, _) = reference_coordinates(options);
+		self.rectangle(x_coord(x0), Graph(0.0), x_coord(x1), Graph(1.0), options);
+		self.reference_label(
+			x_coord((x0 + x1) / 2.0),
+			Graph(1.0),
+			AlignCenter,
+			(0.0, -1.0),
+			options,
+		);
+		self
+	}
+
 	/// Specifies the location and other properties of the legend
 	/// # Arguments
 	/// * `x` - X coordinate of the legend
@@ -366,6 +370,8 @@ impl Axes2D
 	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
 	///     * `LineWidth` - Sets the width of the line
 	///     * `LineStyle` - Sets the style of the line
+	///     * `VariableLineWidth` - Sets the width of the line at each point
+	///     * `VariableLineStyle` - Sets the style of each segment of the line
 	///     * `Color` - Sets the color
 	pub fn lines<
 		'l,
@@ -378,9 +384,19 @@ impl Axes2D
 	) -> &'l mut Self
 	{
 		let (data, num_rows, num_cols) = generate_data!(options, x, y);
-		self.common.elems.push(PlotElement::new_plot(
-			Lines, data, num_rows, num_cols, options,
-		));
+		let variable_style = options
+			.iter()
+			.any(|o| matches!(o, VariableLineWidth(_) | VariableLineStyle(_)));
+		self.common.elems.push(
+			if variable_style
+			{
+				PlotElement::new_line_segments(data, num_rows, num_cols, options)
+			}
+			else
+			{
+				PlotElement::new_plot(Lines, data, num_rows, num_cols, options)
+			},
+		);
 		self
 	}
 
@@ -392,6 +408,8 @@ impl Axes2D
 	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
 	///     * `PointSymbol` - Sets symbol for each point
 	///     * `PointSize` - Sets the size of each point
+	///     * `VariablePointSymbol` - Sets the symbol of each point individually
+	///     * `VariablePointSize`, `VariablePointArea` - Sets the size of each point individually
 	///     * `Color` - Sets the color
 	pub fn points<
 		'l,
@@ -922,6 +940,184 @@ impl Axes2D
 		self
 	}
 
+	/// Places the categories at integer positions along the X axis and labels them with custom ticks.
+	/// Long labels are rotated so they don't overlap.
+	fn set_x_categories(&mut self, categories: &[&str])
+	{
+		let mut label_options = vec![];
+		if categories
+			.iter()
+			.any(|c| c.chars().count() > MAX_UNROTATED_CATEGORY_LEN)
+		{
+			label_options.push(Rotate(45.0));
+			label_options.push(TextAlign(AlignRight));
+		}
+		// The tick labels are format strings, so the categories are taken literally by escaping the
+		// quotes and the `%`.
+		self.set_x_ticks_custom(
+			categories
+				.iter()
+				.enumerate()
+				.map(|(i, &c)| Major(i as f64, Fix(escape(c).replace('%', "%%")))),
+			&[],
+			&label_options,
+		);
+		self.set_x_range(Fix(-0.5), Fix(categories.len() as f64 - 0.5));
+	}
+
+	/// Plot a bar chart of named categories. The categories are placed at integer positions along the X axis
+	/// and are used as labels for the X axis ticks.
+	///
+	/// # Arguments
+	/// * `categories` - Names of the categories
+	/// * `values` - Height of the bar of each category
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the plot element. The relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `LineWidth` - Sets the width of the border
+	///     * `LineStyle` - Sets the style of the border
+	///     * `BorderColor` - Sets the color of the border
+	///     * `Color` - Sets the color of the bar fill
+	///     * `FillAlpha` - Sets the transparency of the bar fill
+	///     * `BoxWidth` - The first element sets the width of the bars (default is 0.8)
+	pub fn bar<'l, T: DataType, V: IntoIterator<Item = T>>(
+		&'l mut self, categories: &[&str], values: V, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.bars(categories, iter::once(values), GroupedBars, &[options])
+	}
+
+	/// Plot several bar series sharing the same named categories, either grouped side by side or stacked.
+	/// The categories are placed at integer positions along the X axis and are used as labels for the X axis ticks.
+	///
+	/// # Arguments
+	/// * `categories` - Names of the categories
+	/// * `series` - Heights of the bars, one collection per series
+	/// * `layout` - Whether to group or stack the bars of each category
+	/// * `options` - Array of PlotOption<&str> arrays, one for each series, controlling the appearance of the series.
+	///   Series without a corresponding entry use the default appearance. The relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `LineWidth` - Sets the width of the border
+	///     * `LineStyle` - Sets the style of the border
+	///     * `BorderColor` - Sets the color of the border
+	///     * `Color` - Sets the color of the bar fill
+	///     * `FillAlpha` - Sets the transparency of the bar fill
+	///     * `BoxWidth` - The first element sets the total width of the bars of each category (default is 0.8).
+	///       Only read from the options of the first series.
+	pub fn bars<'l, T: DataType, V: IntoIterator<Item = T>, S: IntoIterator<Item = V>>(
+		&'l mut self, categories: &[&str], series: S, layout: BarLayout,
+		options: &[&[PlotOption<&str>]],
+	) -> &'l mut Self
+	{
+		let series: Vec<Vec<f64>> = series
+			.into_iter()
+			.map(|v| {
+				v.into_iter()
+					.take(categories.len())
+					.map(|v| v.get())
+					.collect()
+			})
+			.collect();
+
+		let mut width = 0.8;
+		if let Some(first_options) = options.first()
+		{
+			first_opt! {first_options,
+				BoxWidth(ref w) =>
+				{
+					if let Some(&w) = w.first()
+					{
+						width = w;
+					}
+				}
+			}
+		}
+
+		let mut positive_base = vec![0.0; categories.len()];
+		let mut negative_base = vec![0.0; categories.len()];
+		for (j, values) in series.iter().enumerate()
+		{
+			let mut x_low = Vec::with_capacity(values.len());
+			let mut x_high = Vec::with_capacity(values.len());
+			let mut y_low = Vec::with_capacity(values.len());
+			let mut y_high = Vec::with_capacity(values.len());
+			for (i, &v) in values.iter().enumerate()
+			{
+				let (low, high, base) = match layout
+				{
+					GroupedBars =>
+					{
+						let bar_width = width / series.len() as f64;
+						let low = i as f64 - width / 2.0 + j as f64 * bar_width;
+						(low, low + bar_width, 0.0)
+					}
+					StackedBars =>
+					{
+						let base = if v >= 0.0
+						{
+							&mut positive_base[i]
+						}
+						else
+						{
+							&mut negative_base[i]
+						};
+						let old_base = *base;
+						*base += v;
+						(i as f64 - width / 2.0, i as f64 + width / 2.0, old_base)
+					}
+				};
+				x_low.push(low);
+				x_high.push(high);
+				y_low.push(base);
+				y_high.push(base + v);
+			}
+
+			let series_options: Vec<_> = options
+				.get(j)
+				.map(|o| {
+					o.iter()
+						.filter(|o| !matches!(o, BoxWidth(_)))
+						.cloned()
+						.collect()
+				})
+				.unwrap_or_default();
+			self.box_xy_error_low_high(
+				x_low.iter().zip(&x_high).map(|(l, h)| (l + h) / 2.0),
+				y_low.iter().zip(&y_high).map(|(l, h)| (l + h) / 2.0),
+				&x_low,
+				&x_high,
+				&y_low,
+				&y_high,
+				&series_options,
+			);
+		}
+		self.set_x_categories(categories);
+		self
+	}
+
+	/// Plot a 2D scatter-plot of named categories with lines connecting each data point.
+	/// The categories are placed at integer positions along the X axis and are used as labels for the X axis ticks.
+	///
+	/// # Arguments
+	/// * `categories` - Names of the categories
+	/// * `values` - y values, one per category
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the plot element. The relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `LineWidth` - Sets the width of the line
+	///     * `LineStyle` - Sets the style of the line
+	///     * `Color` - Sets the color
+	pub fn lines_categorical<'l, T: DataType, V: IntoIterator<Item = T>>(
+		&'l mut self, categories: &[&str], values: V, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.lines(
+			0..categories.len(),
+			values.into_iter().take(categories.len()),
+			options,
+		);
+		self.set_x_categories(categories);
+		self
+	}
+
 	/// Draws an image from a rectangular array of data by connecting the individual datapoints with polygons.
 	///
 	/// #Arguments:
@@ -950,13 +1146,325 @@ impl Axes2D
 		self
 	}
 
+	/// Draws a filled contour map from a rectangular array of data. Each band of values between two successive
+	/// levels is drawn in a single color picked from the palette, and the color bar is replaced by a stepped one
+	/// with ticks at the levels. Values outside the range of the levels are left blank.
+	///
+	/// The bands are colored by their index, so the color bar range is set to go from 0 to the number of bands,
+	/// with the ticks labeled by the levels and the palette's maximum number of colors set to the number of bands.
+	/// Each of these is only set if it is still automatic, so after an earlier call to this method (or setting them
+	/// explicitly) the color bar is left alone. Multiple filled contour plots on the same axes should therefore use
+	/// the same number of levels.
+	///
+	/// #Arguments:
+	/// * `mat` - Row-major 2D array signifying the value of the datapoints. The X and Y coordinates of the datapoints are determined automatically,
+	///   and optionally scaled using the `dimensions` argument.
+	/// * `num_rows` - Number of rows in the data array
+	/// * `num_cols` - Number of columns in the data array
+	/// * `dimensions` - Optional X and Y coordinates of the first and last data points (with the rest of the coordinates spaced evenly between).
+	///   By default this will be `(0, 0)` and `(num_cols - 1, num_rows - 1)`. Passing reversed coordinates
+	///   (e.g. `x1 > x2`) mirrors the plot.
+	/// * `levels` - The boundaries of the bands. There must be at least two, and they must be increasing.
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the contours. Relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `BorderColor` - Draws the contour lines at the levels in this color, with each line labeled with its level value.
+	///       The lines are traced by gnuplot, which requires gnuplot 5.0 or newer.
+	///     * `LineWidth` - Sets the width of the contour lines
+	///     * `LineStyle` - Sets the style of the contour lines
+	pub fn filled_contours<
+		'l,
+		T: DataType,
+		X: IntoIterator<Item = T>,
+		Tl: DataType,
+		L: IntoIterator<Item = Tl>,
+	>(
+		&'l mut self, mat: X, num_rows: usize, num_cols: usize,
+		dimensions: Option<(f64, f64, f64, f64)>, levels: L, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		let levels: Vec<f64> = levels.into_iter().map(|l| l.get()).collect();
+		assert!(
+			levels.len() >= 2,
+			"Need at least 2 levels for filled contours"
+		);
+		assert!(
+			levels.windows(2).all(|l| l[0] < l[1]),
+			"The levels must be increasing!"
+		);
+		let num_bands = levels.len() - 1;
+
+		let data: Vec<f64> = mat.into_iter().map(|v| v.get()).collect();
+		assert!(
+			data.len() == num_rows * num_cols,
+			"Filled contours need {} values for a {}x{} matrix, got {}",
+			num_rows * num_cols,
+			num_rows,
+			num_cols,
+			data.len()
+		);
+		let (x1, y1, x2, y2) =
+			dimensions.unwrap_or((0.0, 0.0, num_cols as f64 - 1.0, num_rows as f64 - 1.0));
+
+		// The matrix is always drawn with increasing coordinates, so flip the data to keep the
+		// first data point at `(x1, y1)`.
+		let data: Vec<f64> = (0..num_rows * num_cols)
+			.map(|i| {
+				let (mut row, mut col) = (i / num_cols, i % num_cols);
+				if x1 > x2
+				{
+					col = num_cols - 1 - col;
+				}
+				if y1 > y2
+				{
+					row = num_rows - 1 - row;
+				}
+				data[row * num_cols + col]
+			})
+			.collect();
+
+		// Replace each value by the center of its band, so that it picks out a single color from the
+		// discretized palette.
+		let bands: Vec<f64> = data
+			.iter()
+			.map(|&v| {
+				if (levels[0]..=levels[num_bands]).contains(&v)
+				{
+					levels[1..num_bands].iter().filter(|&&l| l <= v).count() as f64 + 0.5
+				}
+				else
+				{
+					f64::NAN
+				}
+			})
+			.collect();
+
+		self.common.elems.push(PlotElement::new_plot_matrix(
+			Image,
+			false,
+			bands,
+			num_rows,
+			num_cols,
+			dimensions,
+			options.to_one_way_owned(),
+		));
+
+		if self.common.palette_max_colors.is_none()
+		{
+			self.common.palette_max_colors = Some(num_bands as u32);
+		}
+		let cb_axis = &mut self.common.cb_axis;
+		if let (Auto, Auto) = (cb_axis.min, cb_axis.max)
+		{
+			cb_axis.set_range(Fix(0.0), Fix(num_bands as f64));
+		}
+		if let TickType::Auto(Auto, 0) = cb_axis.tick_type
+		{
+			cb_axis.set_ticks_custom(
+				levels
+					.iter()
+					.enumerate()
+					.map(|(i, l)| Major(i as f64, Fix(l.to_string()))),
+				vec![],
+				vec![],
+			);
+		}
+
+		let mut line_options = vec![];
+		first_opt! {options,
+			BorderColor(ref c) =>
+			{
+				line_options.push(Color(c.to_one_way_owned()));
+			}
+		}
+		if line_options.is_empty()
+		{
+			return self;
+		}
+		first_opt! {options,
+			LineWidth(w) =>
+			{
+				line_options.push(LineWidth(w));
+			}
+		}
+		first_opt! {options,
+			LineStyle(ref d) =>
+			{
+				line_options.push(LineStyle(d.clone()));
+			}
+		}
+
+		self.common.elems.push(PlotElement::new_plot_contours(
+			data,
+			num_rows,
+			num_cols,
+			(x1, y1, x2, y2),
+			levels,
+			line_options,
+		));
+		self
+	}
+
+	/// Adds an inset axes, e.g. to show a zoomed-in view of a part of this plot. Returns the newly
+	/// created axes, which can be used like any other `Axes2D`.
+	///
+	/// The inset is placed in the graph coordinates of this axes, i.e. `(0, 0)` is the bottom left
+	/// corner of the plot area and `(1, 1)` is the top right one. It is drawn after this axes, within
+	/// the same multiplot, on top of an opaque background.
+	/// # Arguments
+	/// * `x` - X coordinate of the bottom left corner of the plot area of the inset
+	/// * `y` - Y coordinate of the bottom left corner of the plot area of the inset
+	/// * `w` - Width of the plot area of the inset
+	/// * `h` - Height of the plot area of the inset
+	pub fn inset(&mut self, x: f64, y: f64, w: f64, h: f64) -> &mut Axes2D
+	{
+		let background = self
+			.common
+			.theme
+			.as_ref()
+			.and_then(|t| t.background().cloned())
+			.unwrap_or_else(|| "white".into());
+		self.rectangle(
+			Graph(x),
+			Graph(y),
+			Graph(x + w),
+			Graph(y + h),
+			&[Color(background.to_ref()), Layer(Front), Clip(false)],
+		);
+		let mut inset = Axes2D::new();
+		inset.inset_area = Some((x, y, w, h));
+		inset.common.theme = self.common.theme.clone();
+		self.insets.push(inset);
+		self.insets.last_mut().unwrap()
+	}
+
+	/// Marks the region shown by an inset with a rectangle, and connects it to the inset with two lines.
+	///
+	/// The region is taken from the X and Y ranges of the inset, so they should be set before calling
+	/// this. An automatic end of a range is taken to be the corresponding edge of the plot area.
+	/// # Arguments
+	/// * `index` - Index of the inset, in the order the insets were created with `inset`. Panics if
+	///   there is no such inset.
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the marks. Relevant options are:
+	///   * `Color` - Specifies the color of the rectangle and the lines
+	///   * `LineStyle` - Specifies the style of the rectangle and the lines
+	///   * `LineWidth` - Specifies the width of the rectangle and the lines
+	pub fn mark_inset<'l>(&'l mut self, index: usize, options: &[PlotOption<&str>])
+		-> &'l mut Self
+	{
+		let inset = self.insets.get(index).unwrap_or_else(|| {
+			panic!(
+				"Cannot mark the inset {index}: the axes only have {} insets",
+				self.insets.len()
+			)
+		});
+		let (x, y, w, h) = inset.inset_area.unwrap();
+		let bound = |v, edge| match v
+		{
+			Fix(v) => Axis(v),
+			Auto => Graph(edge),
+		};
+		let (x1, x2) = (
+			bound(inset.common.x_axis.min, 0.0),
+			bound(inset.common.x_axis.max, 1.0),
+		);
+		let (y1, y2) = (
+			bound(inset.common.y_axis.min, 0.0),
+			bound(inset.common.y_axis.max, 1.0),
+		);
+
+		let mut color = "black".into();
+		first_opt! {options,
+			Color(ref c) =>
+			{
+				color = c.clone();
+			}
+		}
+		let mut region_options = vec![FillAlpha(0.0), BorderColor(color), Layer(Front)];
+		region_options.extend_from_slice(options);
+		self.rectangle(x1, y1, x2, y2, &region_options);
+
+		// Connect the edge of the region facing the inset with the edge of the inset facing the
+		// region, assuming the inset is placed on the opposite half of the plot.
+		let mut line_options = vec![ArrowType(NoArrow)];
+		line_options.extend_from_slice(options);
+		let (region_y, inset_y) = if y + h / 2.0 >= 0.5
+		{
+			(y2, y)
+		}
+		else
+		{
+			(y1, y + h)
+		};
+		self.arrow(x1, region_y, Graph(x), Graph(inset_y), &line_options);
+		self.arrow(x2, region_y, Graph(x + w), Graph(inset_y), &line_options);
+		self
+	}
+
+	pub(crate) fn hide_captions(&mut self)
+	{
+		for e in &mut self.common.elems
+		{
+			e.hide_caption();
+		}
+	}
+
+	pub(crate) fn set_theme(&mut self, theme: Option<&Theme>)
+	{
+		self.common.theme = theme.cloned();
+		for inset in &mut self.insets
+		{
+			inset.set_theme(theme);
+		}
+	}
+
+	pub(crate) fn has_insets(&self) -> bool
+	{
+		!self.insets.is_empty()
+	}
+
 	pub(crate) fn write_out(
 		&self, data_directory: Option<&str>, writer: &mut dyn Writer, auto_layout: bool,
-		version: GnuplotVersion,
+		version: GnuplotVersion, shared: SharedLayout,
+	)
+	{
+		self.write_out_plot(data_directory, writer, auto_layout, version, None, shared);
+		self.write_out_insets(
+			data_directory,
+			writer,
+			auto_layout,
+			version,
+			"_inset",
+			shared,
+		);
+	}
+
+	/// Writes out this axes without its insets. If this axes is an inset, `parent_area` is the
+	/// prefix of the variables holding the plot area of its parent.
+	pub(crate) fn write_out_plot(
+		&self, data_directory: Option<&str>, writer: &mut dyn Writer, auto_layout: bool,
+		version: GnuplotVersion, parent_area: Option<&str>, shared: SharedLayout,
 	)
 	{
-		self.common.write_out_commands(writer, auto_layout, version);
-		self.border_options.write_out(writer, version);
+		self.common
+			.write_out_commands(writer, auto_layout, version, shared);
+		if let (Some((x, y, w, h)), Some(p)) = (self.inset_area, parent_area)
+		{
+			let mut write_margin = |margin, lo, hi, v: f64| {
+				writeln!(
+					writer,
+					"set {margin} at screen {p}_{lo} + {v:.12e} * ({p}_{hi} - {p}_{lo})"
+				);
+			};
+			write_margin("lmargin", "left", "right", x);
+			write_margin("rmargin", "left", "right", x + w);
+			write_margin("bmargin", "bottom", "top", y);
+			write_margin("tmargin", "bottom", "top", y + h);
+		}
+		let default_locations = Bottom as i32 | Left as i32 | Top as i32 | Right as i32;
+		self.common
+			.themed_border(self.border_options.as_ref(), default_locations)
+			.unwrap_or_else(|| BorderOptions::new(default_locations))
+			.write_out(writer, version);
 		let mut grid_axes = vec![];
 		if self.common.x_axis.grid
 		{
@@ -971,9 +1479,13 @@ impl Axes2D
 			grid_axes.push(self.common.cb_axis.axis);
 		}
 		self.common.write_grid_options(writer, &grid_axes, version);
-		for arrow in &self.arrows
+		for (tag, arrow) in in_z_order(&self.arrows, ArrowData::z_order)
+		{
+			arrow.write_out(tag, writer);
+		}
+		for (tag, object) in in_z_order(&self.objects, ObjectData::z_order)
 		{
-			arrow.write_out(writer);
+			object.write_out(tag, writer, version);
 		}
 		if let Some(l) = self.legend.as_ref()
 		{
@@ -983,12 +1495,86 @@ impl Axes2D
 			.write_out_elements("plot", data_directory, writer, version);
 	}
 
-	pub(crate) fn reset_state(&self, writer: &mut dyn Writer)
+	/// Writes out the insets, after this axes has been plotted. The plot area of this axes is stored
+	/// in variables starting with `prefix`.
+	fn write_out_insets(
+		&self, data_directory: Option<&str>, writer: &mut dyn Writer, auto_layout: bool,
+		version: GnuplotVersion, prefix: &str, shared: SharedLayout,
+	)
 	{
-		self.common.reset_state(writer);
-		for arrow in &self.arrows
+		if self.insets.is_empty()
+		{
+			return;
+		}
+		let w = writer;
+		// The GPVAL_TERM_{X,Y}{MIN,MAX} are scaled down by GPVAL_TERM_SCALE, unlike the sizes.
+		for (name, val, size) in [
+			("left", "XMIN", "XSIZE"),
+			("right", "XMAX", "XSIZE"),
+			("bottom", "YMIN", "YSIZE"),
+			("top", "YMAX", "YSIZE"),
+		]
+		{
+			writeln!(
+				w,
+				"{prefix}_{name} = real(GPVAL_TERM_{val}) * GPVAL_TERM_SCALE / GPVAL_TERM_{size}"
+			);
+		}
+		self.reset_plot_state(w, shared);
+		for (i, inset) in self.insets.iter().enumerate()
+		{
+			if i > 0
+			{
+				self.insets[i - 1].reset_state(w, SharedLayout::default());
+			}
+			let inset_data_directory = data_directory.and_then(|d| {
+				let out_path = path::Path::new(d).join(format!("inset_{i}"));
+				std::fs::create_dir_all(&out_path).ok();
+				out_path.to_str().map(|s| s.to_string())
+			});
+			inset.write_out_plot(
+				inset_data_directory.as_deref(),
+				w,
+				auto_layout,
+				version,
+				Some(prefix),
+				SharedLayout::default(),
+			);
+			if auto_layout
+			{
+				// Insets do not take up a cell of the layout.
+				writeln!(w, "set multiplot previous");
+			}
+			inset.write_out_insets(
+				inset_data_directory.as_deref(),
+				w,
+				auto_layout,
+				version,
+				&format!("{prefix}_{i}"),
+				SharedLayout::default(),
+			);
+		}
+	}
+
+	pub(crate) fn reset_state(&self, writer: &mut dyn Writer, shared: SharedLayout)
+	{
+		self.reset_plot_state(writer, shared);
+		for inset in &self.insets
+		{
+			inset.reset_state(writer, SharedLayout::default());
+		}
+	}
+
+	fn reset_plot_state(&self, writer: &mut dyn Writer, shared: SharedLayout)
+	{
+		self.common.reset_state(writer, shared);
+		for (tag, arrow) in in_z_order(&self.arrows, ArrowData::z_order)
+		{
+			arrow.reset_state(tag, writer);
+		}
+		for (tag, object) in in_z_order(&self.objects, ObjectData::z_order)
 		{
-			arrow.reset_state(writer);
+			object.reset_state(tag, writer);
 		}
 		if let Some(l) = self.legend.as_ref()
 		{
@@ -997,6 +1583,29 @@ impl Axes2D
 	}
 }
 
+/// Category labels longer than this are rotated
+const MAX_UNROTATED_CATEGORY_LEN: usize = 8;
+
+type CoordinateConstructor = fn(f64) -> Coordinate;
+
+/// Returns the constructors of the axis coordinates selected by the `Axes` option
+fn reference_coordinates(
+	options: &[PlotOption<&str>],
+) -> (CoordinateConstructor, CoordinateConstructor)
+{
+	let mut axes = (X1, Y1);
+	first_opt! {options,
+		Axes(x, y) =>
+		{
+			axes = (x, y);
+		}
+	}
+	(
+		if axes.0 == X2 { Axis2 } else { Axis },
+		if axes.1 == Y2 { Axis2 } else { Axis },
+	)
+}
+
 impl AxesCommonPrivate for Axes2D
 {
 	fn get_common_data(&self) -> &AxesCommonData
@@ -1011,3 +1620,67 @@ impl AxesCommonPrivate for Axes2D
 }
 
 impl AxesCommon for Axes2D {}
+
+#[test]
+fn categories_test()
+{
+	use crate::figure::Figure;
+
+	let mut fg = Figure::new();
+	fg.axes2d().bar(&["\"A\"", "50%"], [1., 2.], &[]);
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	assert!(script.contains(r#"("\"A\"" 0.000000000000e0 0,"50%%" 1.000000000000e0 0)"#));
+}
+
+#[test]
+#[should_panic(expected = "Cannot mark the inset 1: the axes only have 1 insets")]
+fn mark_inset_missing_test()
+{
+	let mut axes = Axes2D::new();
+	axes.inset(0.6, 0.6, 0.3, 0.3);
+	axes.mark_inset(1, &[]);
+}
+
+#[test]
+fn filled_contours_test()
+{
+	use crate::figure::Figure;
+
+	let mut fg = Figure::new();
+	let data = [0., 1., 2., 3.];
+	fg.axes2d()
+		.filled_contours(
+			data,
+			2,
+			2,
+			None,
+			[0., 1., 3.],
+			&[BorderColor("black".into())],
+		)
+		.filled_contours(data, 2, 2, None, [0., 1., 2., 3.], &[]);
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	// The second call keeps the color bar of the first one.
+	assert!(script.contains("set palette maxcolors 2\n"));
+	assert!(script.contains(&format!("set cbrange [{:.12e}:{:.12e}]\n", 0.0, 2.0)));
+	assert!(script.contains(
+		r#"set cbtics ("0" 0.000000000000e0 0,"1" 1.000000000000e0 0,"3" 2.000000000000e0 0)"#
+	));
+	// Only the first call draws contour lines, traced by gnuplot.
+	assert_eq!(script.matches("\nset table").count(), 1);
+	assert!(script.contains("set table $contours1\nsplot "));
+	assert!(script.contains("$contours1 using 1:2 with lines "));
+	assert!(script.contains(
+		"$contours1 using 1:2:(sprintf(\"%g\", $3)) every 20::4 with labels center textcolor rgb \"black\""
+	));
+}
+
+#[test]
+#[should_panic(expected = "Filled contours need 4 values for a 2x2 matrix, got 3")]
+fn filled_contours_size_test()
+{
+	Axes2D::new().filled_contours([0., 1., 2.], 2, 2, None, [0., 1.], &[]);
+}
@@ -3,8 +3,10 @@
 // All rights reserved. Distributed under LGPL 3.0. For full terms see the file LICENSE.
 
 use crate::axes_common::*;
+use crate::coordinates::*;
 use crate::datatype::*;
 use crate::options::*;
+use crate::style::Theme;
 use crate::util::OneWayOwned;
 use crate::writer::Writer;
 use std::borrow::Borrow;
@@ -13,6 +15,7 @@ enum View
 {
 	PitchYaw(f64, f64),
 	Map,
+	Projection(ProjectionPlane),
 }
 
 impl View
@@ -29,12 +32,118 @@ impl View
 			{
 				writer.write_str("set view map\n");
 			}
+			Self::Projection(plane) =>
+			{
+				writeln!(
+					writer,
+					"set view projection {}",
+					match plane
+					{
+						ProjectionXY => "xy",
+						ProjectionXZ => "xz",
+						ProjectionYZ => "yz",
+					}
+				);
+			}
 		}
 	}
+}
+
+/// The largest number of voxels along each axis of a voxel grid. Gnuplot allocates the cube of
+/// this many voxels.
+const MAX_VOXEL_GRID_SIZE: usize = 256;
 
-	fn reset_state(&self, writer: &mut dyn Writer)
+struct VoxelGridData
+{
+	size: usize,
+	bounds: (f64, f64, f64, f64, f64, f64),
+	/// Rows of the voxel center coordinates and the value.
+	data: Vec<f64>,
+}
+
+impl VoxelGridData
+{
+	fn new(
+		grid: &[f64], nx: usize, ny: usize, nz: usize, bounds: (f64, f64, f64, f64, f64, f64),
+	) -> Self
 	{
-		writer.write_str("unset view\n");
+		assert!(
+			nx >= 2 && ny >= 2 && nz >= 2,
+			"Voxel grids need at least 2 points along each axis"
+		);
+		assert!(grid.len() >= nx * ny * nz, "Not enough voxel grid values");
+
+		// Gnuplot only supports cubic voxel grids, so place each value at the center of the voxel
+		// nearest to its point.
+		let size = nx.max(ny).max(nz);
+		assert!(
+			size <= MAX_VOXEL_GRID_SIZE,
+			"Voxel grids can have at most {} points along each axis, got {}",
+			MAX_VOXEL_GRID_SIZE,
+			size
+		);
+		let (x1, y1, z1, x2, y2, z2) = bounds;
+		let coord = |v: usize, n: usize, c1: f64, c2: f64| {
+			let voxel = (v as f64 * (size - 1) as f64 / (n - 1) as f64).round();
+			c1 + (c2 - c1) * voxel / (size - 1) as f64
+		};
+
+		let mut data = Vec::with_capacity(nx * ny * nz * 4);
+		for k in 0..nz
+		{
+			for j in 0..ny
+			{
+				for i in 0..nx
+				{
+					data.push(coord(i, nx, x1, x2));
+					data.push(coord(j, ny, y1, y2));
+					data.push(coord(k, nz, z1, z2));
+					data.push(grid[(k * ny + j) * nx + i]);
+				}
+			}
+		}
+
+		VoxelGridData { size, bounds, data }
+	}
+
+	fn write_out(&self, grid: usize, data_directory: Option<&str>, w: &mut dyn Writer)
+	{
+		let (x1, y1, z1, x2, y2, z2) = self.bounds;
+		let name = voxel_grid_name(grid);
+		writeln!(w, "set vgrid {} size {}", name, self.size);
+		writeln!(w, "set vxrange [{:.12e}:{:.12e}]", x1.min(x2), x1.max(x2));
+		writeln!(w, "set vyrange [{:.12e}:{:.12e}]", y1.min(y2), y1.max(y2));
+		writeln!(w, "set vzrange [{:.12e}:{:.12e}]", z1.min(z2), z1.max(z2));
+		writeln!(w, "vclear {}", name);
+
+		// Each data point lands on the center of a voxel, so a small radius makes it fill only that voxel.
+		let spacing = [(x2 - x1).abs(), (y2 - y1).abs(), (z2 - z1).abs()]
+			.iter()
+			.fold(f64::INFINITY, |a, &b| a.min(b))
+			/ (self.size - 1) as f64;
+		let write_data = |w: &mut dyn Writer| {
+			for d in &self.data
+			{
+				w.write_le_f64(*d);
+			}
+		};
+		let source = write_data_file(data_directory, &format!("vgrid{grid}.bin"), write_data);
+		writeln!(
+			w,
+			r#"vfill "{}" binary endian=little record={} format="%float64" using 1:2:3:({:.12e}):4"#,
+			source,
+			self.data.len() / 4,
+			spacing / 4.0
+		);
+		if source == "-"
+		{
+			write_data(w);
+		}
+	}
+
+	fn reset_state(&self, grid: usize, w: &mut dyn Writer)
+	{
+		writeln!(w, "unset vgrid {}", voxel_grid_name(grid));
 	}
 }
 
@@ -50,6 +159,16 @@ pub struct Axes3D
 	contour_style: ContourStyle,
 	contour_label: AutoOption<String>,
 	view: Option<View>,
+	view_scale: Option<(f64, f64)>,
+	view_equal: Option<EqualAxes3D>,
+	view_azimuth: Option<f64>,
+	xy_plane: Option<XYPlane>,
+	box_depth: Option<f64>,
+	pm3d_lighting: Option<(f64, f64)>,
+	voxel_grids: Vec<VoxelGridData>,
+	border_options: Option<BorderOptions>,
+	arrows: Vec<ArrowData>,
+	legend: Option<LegendData>,
 }
 
 impl Axes3D
@@ -66,9 +185,93 @@ impl Axes3D
 			contour_style: Linear,
 			contour_label: Auto,
 			view: None,
+			view_scale: None,
+			view_equal: None,
+			view_azimuth: None,
+			xy_plane: None,
+			box_depth: None,
+			pm3d_lighting: None,
+			voxel_grids: vec![],
+			border_options: None,
+			arrows: vec![],
+			legend: None,
 		}
 	}
 
+	pub(crate) fn set_theme(&mut self, theme: Option<&Theme>)
+	{
+		self.common.theme = theme.cloned();
+	}
+
+	/// Sets the properties of the plot border
+	///
+	/// # Arguments
+	///
+	/// * `front` - Whether or not to draw the border above or below the plot contents
+	/// * `locations` - Which edges of the box surrounding the plot to draw. Pass all of them to draw the full box.
+	/// * `options` - Array of PlotOption controlling the appearance of the border. Relevant options are:
+	///      * `Color` - Specifies the color of the border
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	pub fn set_border<'l>(
+		&'l mut self, front: bool, locations: &[BorderLocation3D], options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		let mut border_options = BorderOptions::new(locations.iter().fold(0, |f, &l| f | l as i32));
+		border_options.front = front;
+		border_options.options = options.to_one_way_owned();
+		self.border_options = Some(border_options);
+		self
+	}
+
+	/// Adds an arrow to the plot. The arrow is drawn from `from` to `to` with the arrow point towards `to`.
+	/// # Arguments
+	/// * `from` - Coordinates of the arrow start
+	/// * `to` - Coordinates of the arrow end
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the arrowhead and arrow shaft. Relevant options are:
+	///      * `ArrowType` - Specifies the style of the arrow head (or an option to omit it)
+	///      * `ArrowSize` - Sets the size of the arrow head (in graph units)
+	///      * `Color` - Specifies the color of the arrow
+	///      * `LineStyle` - Specifies the style of the arrow shaft
+	///      * `LineWidth` - Specifies the width of the arrow shaft
+	///      * `Layer` - Specifies the layer the arrow is drawn on (`Behind` is treated as `Back`)
+	///      * `ZOrder` - Specifies the drawing order relative to the other arrows
+	pub fn arrow<'l>(
+		&'l mut self, from: Coordinate3D, to: Coordinate3D, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		self.arrows.push(ArrowData {
+			from: vec![from.x, from.y, from.z],
+			to: vec![to.x, to.y, to.z],
+			plot_options: options.to_one_way_owned(),
+		});
+		self
+	}
+
+	/// Specifies the location and other properties of the legend
+	/// # Arguments
+	/// * `x` - X coordinate of the legend. Note that in 3D plots `Graph` coordinates are relative to the box surrounding the plot.
+	/// * `y` - Y coordinate of the legend
+	/// * `legend_options` - Array of LegendOption options
+	/// * `text_options` - Array of LabelOption options specifying the appearance of the plot titles. Valid options are:
+	///     * `Font`
+	///     * `TextColor`
+	///     * `TextAlign(AlignLeft)`
+	///     * `TextAlign(AlignRight)`
+	pub fn set_legend<'l>(
+		&'l mut self, x: Coordinate, y: Coordinate, legend_options: &[LegendOption<&str>],
+		text_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.legend = Some(LegendData {
+			x,
+			y,
+			legend_options: legend_options.to_one_way_owned(),
+			text_options: text_options.to_one_way_owned(),
+		});
+		self
+	}
+
 	/// Draws a 3D surface from a rectangular array of data by connecting the individual datapoints with polygons.
 	///
 	/// #Arguments:
@@ -106,6 +309,8 @@ impl Axes3D
 	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
 	///     * `PointSymbol` - Sets symbol for each point
 	///     * `PointSize` - Sets the size of each point
+	///     * `VariablePointSymbol` - Sets the symbol of each point individually
+	///     * `VariablePointSize`, `VariablePointArea` - Sets the size of each point individually
 	///     * `Color` - Sets the color
 	pub fn points<
 		'l,
@@ -184,6 +389,119 @@ impl Axes3D
 		self
 	}
 
+	/// Plot a 3D bar chart, with a box standing in for each data point. Boxes start at the XY plane
+	/// and go towards the z value of the datapoint. Requires gnuplot 5.4 or newer.
+	///
+	/// The width of the boxes (along the X axis) is set with [set_box_width()](crate::AxesCommon::set_box_width()),
+	/// and their depth (along the Y axis) with [set_box_depth()](Axes3D::set_box_depth()). The boxes are drawn using pm3d,
+	/// see [set_pm3d_lighting()](Axes3D::set_pm3d_lighting()) to shade their faces.
+	///
+	/// # Arguments
+	/// * `x` - x values (center of the box)
+	/// * `y` - y values (center of the box)
+	/// * `z` - z values
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the plot element. The relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `LineWidth` - Sets the width of the border
+	///     * `LineStyle` - Sets the style of the border
+	///     * `BorderColor` - Sets the color of the border
+	///     * `Color` - Sets the color of the box fill. Use one of the variable color types (e.g. `VariableRGBInteger`
+	///       or `VariableIndex`) to color each box separately, or `PaletteFracColor` to pick a color from the palette.
+	///     * `FillAlpha` - Sets the transparency of the box fill
+	pub fn boxes<
+		'l,
+		Tx: DataType,
+		X: IntoIterator<Item = Tx>,
+		Ty: DataType,
+		Y: IntoIterator<Item = Ty>,
+		Tz: DataType,
+		Z: IntoIterator<Item = Tz>,
+	>(
+		&'l mut self, x: X, y: Y, z: Z, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		let (data, num_rows, num_cols) = generate_data!(options, x, y, z);
+		self.common.elems.push(PlotElement::new_plot(
+			Boxes, data, num_rows, num_cols, options,
+		));
+		self
+	}
+
+	/// Draws a volumetric grid of values, either as isosurfaces or as points colored by their value.
+	/// Requires gnuplot 5.4 or newer.
+	///
+	/// Gnuplot only supports voxel grids with the same number of voxels along each axis, so the
+	/// grid it allocates has the largest of `nx`, `ny` and `nz` voxels along each axis, and each
+	/// value fills the voxel nearest to its point. Along the axes with fewer values, the voxels
+	/// between the points are left empty, so isosurfaces look best with the same number of values
+	/// along each axis. Gnuplot allocates the cube of that many voxels, so each of `nx`, `ny` and
+	/// `nz` can be at most 256.
+	///
+	/// #Arguments:
+	/// * `grid` - 3D array of the values, with the X index varying fastest and the Z index slowest,
+	///   i.e. the value at `(i, j, k)` is at `grid[(k * ny + j) * nx + i]`.
+	/// * `(nx, ny, nz)` - Number of values along the X, Y and Z axes. Each must be between 2 and 256.
+	/// * `bounds` - X, Y and Z coordinates of the first and last values, in the `(x1, y1, z1, x2, y2, z2)` order
+	///   (with the rest of the coordinates spaced evenly between).
+	/// * `style` - How to draw the grid, see `VoxelStyle`
+	/// * `options` - Array of PlotOption<&str> controlling the appearance of the plot element. The relevant options are:
+	///     * `Caption` - Specifies the caption for this dataset. Use an empty string to hide it (default).
+	///     * `Color` - Sets the color of the isosurfaces, or of the points. By default, the points are colored using the palette
+	///     * `FillAlpha` - Sets the transparency of the isosurfaces
+	///     * `PointSymbol` - Sets symbol for each point
+	///     * `PointSize` - Sets the size of each point
+	pub fn voxels<'l, T: DataType, G: IntoIterator<Item = T>>(
+		&'l mut self, grid: G, (nx, ny, nz): (usize, usize, usize),
+		bounds: (f64, f64, f64, f64, f64, f64), style: VoxelStyle, options: &[PlotOption<&str>],
+	) -> &'l mut Self
+	{
+		let grid: Vec<f64> = grid.into_iter().map(|v| v.get()).collect();
+		let idx = self.voxel_grids.len();
+		self.voxel_grids
+			.push(VoxelGridData::new(&grid, nx, ny, nz, bounds));
+		match style
+		{
+			Isosurfaces(levels) =>
+			{
+				for level in levels
+				{
+					self.common.elems.push(PlotElement::new_plot_voxel_grid(
+						Isosurface, idx, level, options,
+					));
+				}
+			}
+			VoxelPoints(threshold) =>
+			{
+				self.common.elems.push(PlotElement::new_plot_voxel_grid(
+					Points, idx, threshold, options,
+				));
+			}
+		}
+		self
+	}
+
+	/// Set the depth (along the Y axis) of boxes in 3D box plots (see [boxes()](Axes3D::boxes())).
+	///
+	/// # Arguments
+	/// * `depth` - Depth of the boxes, in the units of the Y axis
+	pub fn set_box_depth(&mut self, depth: f64) -> &mut Self
+	{
+		self.box_depth = Some(depth);
+		self
+	}
+
+	/// Enables lighting of the surfaces drawn with pm3d (e.g. [surface()](Axes3D::surface()) and [boxes()](Axes3D::boxes())),
+	/// which makes the faces pointing in different directions distinguishable.
+	///
+	/// # Arguments
+	/// * `primary` - Fraction of the illumination coming from the light source, ranging from 0 to 1. The rest is ambient light.
+	/// * `specular` - Strength of the specular highlights, ranging from 0 to 1
+	pub fn set_pm3d_lighting(&mut self, primary: f64, specular: f64) -> &mut Self
+	{
+		self.pm3d_lighting = Some((primary, specular));
+		self
+	}
+
 	/// Sets the 3D view.
 	///
 	/// #Arguments:
@@ -202,6 +520,60 @@ impl Axes3D
 		self
 	}
 
+	/// Sets the view to look straight down at one of the coordinate planes. `ProjectionXY` is
+	/// equivalent to the map view. Requires gnuplot 5.4 or newer.
+	///
+	/// #Arguments:
+	/// * `plane` - Which plane to project onto
+	pub fn set_view_projection(&mut self, plane: ProjectionPlane) -> &mut Self
+	{
+		self.view = Some(View::Projection(plane));
+		self
+	}
+
+	/// Scales the 3D plot.
+	///
+	/// #Arguments:
+	/// * `scale` - Scale of the whole plot, with 1.0 being the default
+	/// * `z_scale` - Additional scale of the Z axis, with 1.0 being the default
+	pub fn set_view_scale(&mut self, scale: f64, z_scale: f64) -> &mut Self
+	{
+		self.view_scale = Some((scale, z_scale));
+		self
+	}
+
+	/// Forces some of the axes to have the same scale, so that e.g. a circle in the XY plane is not
+	/// stretched into an ellipse.
+	///
+	/// #Arguments:
+	/// * `equal` - Which axes to scale equally. `None` lets every axis scale independently (default)
+	pub fn set_view_equal(&mut self, equal: Option<EqualAxes3D>) -> &mut Self
+	{
+		self.view_equal = equal;
+		self
+	}
+
+	/// Rotates the 3D plot in the plane of the screen.
+	///
+	/// #Arguments:
+	/// * `azimuth` - Azimuth, in degrees. Value of 0 has the Z axis pointing up (default).
+	pub fn set_view_azimuth(&mut self, azimuth: f64) -> &mut Self
+	{
+		self.view_azimuth = Some(azimuth);
+		self
+	}
+
+	/// Sets where the XY plane is drawn relative to the Z axis. By default, it floats below the
+	/// bottom of the Z range, use `XYPlaneRelative(0.0)` to make it touch the bottom instead.
+	///
+	/// #Arguments:
+	/// * `plane` - Location of the XY plane
+	pub fn set_xy_plane(&mut self, plane: XYPlane) -> &mut Self
+	{
+		self.xy_plane = Some(plane);
+		self
+	}
+
 	/// Set the label for the Z axis
 	///
 	/// # Arguments
@@ -387,13 +759,53 @@ impl Axes3D
 
 	pub(crate) fn reset_state(&self, writer: &mut dyn Writer)
 	{
-		self.common.reset_state(writer);
-		if let Some(v) = self.view.as_ref()
+		self.common.reset_state(writer, SharedLayout::default());
+		if self.view.is_some()
+			|| self.view_scale.is_some()
+			|| self.view_equal.is_some()
+			|| self.view_azimuth.is_some()
+		{
+			writer.write_str("unset view\n");
+		}
+		if self.xy_plane.is_some()
 		{
-			v.reset_state(writer)
+			writer.write_str("set xyplane relative 0.5\n");
+		}
+		if self.box_depth.is_some()
+		{
+			writer.write_str("unset boxdepth\n");
+		}
+		if self.pm3d_lighting.is_some()
+		{
+			writer.write_str("set pm3d nolighting\n");
+		}
+		if self.has_boxes()
+		{
+			writer.write_str("set pm3d scansautomatic\n");
+		}
+		for (i, grid) in self.voxel_grids.iter().enumerate()
+		{
+			grid.reset_state(i, writer);
+		}
+		if self.border_options.is_some() || self.common.theme.is_some()
+		{
+			writer.write_str("set border 31 front\n");
+		}
+		for (tag, arrow) in in_z_order(&self.arrows, ArrowData::z_order)
+		{
+			arrow.reset_state(tag, writer);
+		}
+		if let Some(l) = self.legend.as_ref()
+		{
+			l.reset_state(writer)
 		};
 	}
 
+	fn has_boxes(&self) -> bool
+	{
+		self.common.elems.iter().any(|e| e.is_boxes())
+	}
+
 	pub(crate) fn write_out(
 		&self, data_directory: Option<&str>, w: &mut dyn Writer, auto_layout: bool,
 		version: GnuplotVersion,
@@ -524,8 +936,9 @@ impl Axes3D
 			});
 		}
 
-		self.common.write_out_commands(w, auto_layout, version);
-		self.z_axis.write_out_commands(w, version);
+		self.common
+			.write_out_commands(w, auto_layout, version, SharedLayout::default());
+		self.z_axis.write_out_commands(w, version, None);
 		let mut grid_axes = vec![];
 		if self.common.x_axis.grid
 		{
@@ -547,7 +960,72 @@ impl Axes3D
 		{
 			v.write_out(w)
 		};
+		if let Some((scale, z_scale)) = self.view_scale
+		{
+			writeln!(w, "set view ,,{:.12e},{:.12e}", scale, z_scale);
+		}
+		if let Some(equal) = self.view_equal
+		{
+			writeln!(
+				w,
+				"set view equal {}",
+				match equal
+				{
+					EqualXY => "xy",
+					EqualXYZ => "xyz",
+				}
+			);
+		}
+		if let Some(azimuth) = self.view_azimuth
+		{
+			writeln!(w, "set view azimuth {:.12e}", azimuth);
+		}
+		match self.xy_plane
+		{
+			Some(XYPlaneRelative(v)) =>
+			{
+				writeln!(w, "set xyplane relative {:.12e}", v);
+			}
+			Some(XYPlaneAt(z)) =>
+			{
+				writeln!(w, "set xyplane at {:.12e}", z);
+			}
+			None => (),
+		}
+		if let Some(depth) = self.box_depth
+		{
+			writeln!(w, "set boxdepth {:.12e}", depth);
+		}
+		if let Some((primary, specular)) = self.pm3d_lighting
+		{
+			writeln!(
+				w,
+				"set pm3d lighting primary {:.12e} specular {:.12e}",
+				primary, specular
+			);
+		}
+		if self.has_boxes()
+		{
+			// Boxes need to be sorted by depth to occlude each other correctly.
+			writeln!(w, "set pm3d depthorder base");
+		}
 		self.common.write_grid_options(w, &grid_axes, version);
+		if let Some(b) = self.common.themed_border(self.border_options.as_ref(), 31)
+		{
+			b.write_out(w, version);
+		}
+		for (tag, arrow) in in_z_order(&self.arrows, ArrowData::z_order)
+		{
+			arrow.write_out(tag, w);
+		}
+		if let Some(l) = self.legend.as_ref()
+		{
+			l.write_out(w)
+		};
+		for (i, grid) in self.voxel_grids.iter().enumerate()
+		{
+			grid.write_out(i, data_directory, w);
+		}
 		self.common
 			.write_out_elements("splot", data_directory, w, version);
 	}
@@ -567,3 +1045,16 @@ impl AxesCommonPrivate for Axes3D
 }
 
 impl AxesCommon for Axes3D {}
+
+#[test]
+fn voxel_grid_test()
+{
+	let values: Vec<f64> = (0..12).map(|v| v as f64).collect();
+	let grid = VoxelGridData::new(&values, 3, 2, 2, (0.0, 0.0, 0.0, 4.0, 2.0, 1.0));
+	assert_eq!(grid.size, 3);
+	// The values are not resampled, only placed on the nearest voxel.
+	assert_eq!(grid.data.len(), 12 * 4);
+	assert_eq!(&grid.data[..4], &[0.0, 0.0, 0.0, 0.0]);
+	assert_eq!(&grid.data[4 * 4..5 * 4], &[2.0, 2.0, 0.0, 4.0]);
+	assert_eq!(&grid.data[11 * 4..], &[4.0, 2.0, 1.0, 11.0]);
+}
@@ -10,11 +10,14 @@ use crate::coordinates::*;
 
 use crate::datatype::*;
 use crate::options::*;
+use crate::style::{StyleCycle, Theme};
+use crate::time_ticks::*;
 use crate::util::{escape, OneWayOwned};
 use crate::writer::*;
 use crate::ColorType;
 use std::borrow::Borrow;
 use std::fs;
+use std::ops::Range;
 use std::path;
 
 pub struct PlotElement
@@ -26,6 +29,8 @@ pub struct PlotElement
 	source_type: DataSourceType,
 	is_3d: bool,
 	options: Vec<PlotOption<String>>,
+	/// Distinct widths and dash types of the segments of a `LineSegments` element.
+	segment_styles: Vec<(f64, DashType)>,
 }
 
 impl PlotElement
@@ -43,6 +48,82 @@ impl PlotElement
 			source_type: Record,
 			is_3d: false,
 			options: options.to_one_way_owned(),
+			segment_styles: vec![],
+		}
+	}
+
+	/// Creates a line whose segments each have their own width and dash type, taken from the
+	/// `VariableLineWidth` and `VariableLineStyle` options. `data` holds the vertices. Each segment is
+	/// drawn as a vector, using one of the `segment_styles` as its arrow style.
+	pub fn new_line_segments(
+		data: Vec<f64>, num_rows: usize, num_cols: usize, options: &[PlotOption<&str>],
+	) -> PlotElement
+	{
+		let widths = options.iter().find_map(|o| match o
+		{
+			VariableLineWidth(w) => Some(&w[..]),
+			_ => None,
+		});
+		let dashes = options.iter().find_map(|o| match o
+		{
+			VariableLineStyle(d) => Some(&d[..]),
+			_ => None,
+		});
+		let mut width = 1.;
+		first_opt! {options,
+			LineWidth(w) =>
+			{
+				width = w;
+			}
+		}
+		let mut dash = Solid;
+		first_opt! {options,
+			LineStyle(ref d) =>
+			{
+				dash = d.clone();
+			}
+		}
+
+		let num_rows = num_rows
+			.min(widths.map_or(num_rows, |w| w.len()))
+			.min(dashes.map_or(num_rows, |d| d.len()));
+		let num_segments = num_rows.saturating_sub(1);
+		let mut segment_styles = vec![];
+		let mut segment_data = Vec::with_capacity(num_segments * (num_cols + 3));
+		for i in 0..num_segments
+		{
+			let row = &data[i * num_cols..(i + 1) * num_cols];
+			let next = &data[(i + 1) * num_cols..(i + 2) * num_cols];
+			// The width is given per vertex, so a segment uses the average of its ends.
+			let style = (
+				widths.map_or(width, |w| (w[i] + w[i + 1]) / 2.),
+				dashes.map_or_else(|| dash.clone(), |d| d[i].clone()),
+			);
+			let style_idx = segment_styles
+				.iter()
+				.position(|s| *s == style)
+				.unwrap_or_else(|| {
+					segment_styles.push(style);
+					segment_styles.len() - 1
+				});
+
+			segment_data.extend_from_slice(&row[..2]);
+			segment_data.push(next[0] - row[0]);
+			segment_data.push(next[1] - row[1]);
+			segment_data.push(style_idx as f64);
+			// Remaining columns, e.g. variable colors, are taken from the first vertex.
+			segment_data.extend_from_slice(&row[2..]);
+		}
+
+		PlotElement {
+			data: segment_data,
+			num_rows: num_segments,
+			num_cols: num_cols + 3,
+			plot_type: LineSegments,
+			source_type: Record,
+			is_3d: false,
+			options: options.to_one_way_owned(),
+			segment_styles,
 		}
 	}
 
@@ -79,78 +160,248 @@ impl PlotElement
 			source_type,
 			is_3d,
 			options,
+			segment_styles: vec![],
+		}
+	}
+
+	pub fn new_plot_voxel_grid(
+		plot_type: PlotType, grid: usize, level: f64, options: &[PlotOption<&str>],
+	) -> PlotElement
+	{
+		PlotElement {
+			data: vec![],
+			num_rows: 0,
+			num_cols: 0,
+			plot_type,
+			source_type: VoxelGrid(grid, level),
+			is_3d: true,
+			options: options.to_one_way_owned(),
+			segment_styles: vec![],
+		}
+	}
+
+	pub fn new_plot_contours(
+		data: Vec<f64>, num_rows: usize, num_cols: usize, dimensions: (f64, f64, f64, f64),
+		levels: Vec<f64>, options: Vec<PlotOption<String>>,
+	) -> PlotElement
+	{
+		PlotElement {
+			data,
+			num_rows,
+			num_cols,
+			plot_type: Lines,
+			source_type: Contours(dimensions, levels),
+			is_3d: false,
+			options,
+			segment_styles: vec![],
 		}
 	}
 
-	fn write_args(&self, source: &str, writer: &mut dyn Writer, version: GnuplotVersion)
+	fn is_empty(&self) -> bool
+	{
+		self.num_rows == 0 && !matches!(self.source_type, VoxelGrid(..))
+	}
+
+	/// Returns the range of the coordinates of the element along the X (or Y) axis it is plotted
+	/// against, or `None` if it has no finite coordinates. Only the positions are taken into
+	/// account (e.g. not the widths of boxes), and 3D elements are ignored. With `positive`, the
+	/// coordinates that can't be shown on a logarithmic axis are ignored too. Like gnuplot's
+	/// autoscaling, this skips the points outside of the fixed `other_range` of the other axis.
+	pub fn extent(
+		&self, is_x: bool, positive: bool, other_range: (AutoOption<f64>, AutoOption<f64>),
+	) -> Option<(f64, f64)>
 	{
-		let options = &self.options;
+		if self.is_3d || self.is_empty()
+		{
+			return None;
+		}
+		let mut values = vec![];
 		match self.source_type
 		{
 			Record =>
 			{
-				write!(
-					writer,
-					r#" "{}" binary endian=little record={} format="%float64" using "#,
-					source, self.num_rows
-				);
-
-				let mut col_idx = 1;
-				while col_idx < self.num_cols + 1
+				let n = self.num_cols;
+				let (other_min, other_max) = match other_range
 				{
-					write!(writer, "{}", col_idx);
-					if col_idx < self.num_cols
+					(Fix(a), Fix(b)) => (a.min(b), a.max(b)),
+					(Fix(a), Auto) => (a, f64::INFINITY),
+					(Auto, Fix(b)) => (f64::NEG_INFINITY, b),
+					(Auto, Auto) => (f64::NEG_INFINITY, f64::INFINITY),
+				};
+				for row in self.data.chunks_exact(n)
+				{
+					let other = if is_x { row[1] } else { row[0] };
+					if other < other_min || other > other_max
 					{
-						writer.write_str(":");
+						continue;
+					}
+					let c = |i: usize| row[i];
+					// A value with a symmetric error
+					let d = |i: usize, j: usize| [row[i] - row[j], row[i] + row[j]];
+					match (is_x, &self.plot_type)
+					{
+						(true, XErrorBars | XErrorLines | XYErrorBars) => values.extend(d(0, 2)),
+						(true, BoxXYError) if n >= 6 => values.extend([c(0), c(2), c(3)]),
+						(true, BoxXYError) => values.extend(d(0, 2)),
+						(true, LineSegments) => values.extend([c(0), c(0) + c(2)]),
+						(true, _) => values.push(c(0)),
+						(false, YErrorBars | YErrorLines) => values.extend(d(1, 2)),
+						(false, BoxErrorBars) if n >= 5 => values.extend([c(1), c(2), c(3)]),
+						(false, BoxErrorBars) => values.extend(d(1, 2)),
+						(false, XYErrorBars) => values.extend(d(1, 3)),
+						(false, BoxXYError) if n >= 6 => values.extend([c(1), c(4), c(5)]),
+						(false, BoxXYError) => values.extend(d(1, 3)),
+						(false, FillBetween) => values.extend([c(1), c(2)]),
+						(false, BoxAndWhisker) => values.extend(&row[1..5]),
+						(false, LineSegments) => values.extend([c(1), c(1) + c(3)]),
+						(false, _) => values.push(c(1)),
 					}
-					col_idx += 1;
 				}
 			}
-			_ =>
+			Array | SizedArray(..) | Contours(..) =>
+			{
+				let (x1, y1, x2, y2) = match self.source_type
+				{
+					SizedArray(x1, y1, x2, y2) | Contours((x1, y1, x2, y2), _) => (x1, y1, x2, y2),
+					_ => (
+						0.,
+						0.,
+						(self.num_cols - 1) as f64,
+						(self.num_rows - 1) as f64,
+					),
+				};
+				let (lo, hi, n) = if is_x
+				{
+					(x1, x2, self.num_cols)
+				}
+				else
+				{
+					(y1, y2, self.num_rows)
+				};
+				// The pixels of an image are centered on the coordinates.
+				let pad = if matches!(self.plot_type, Image) && n > 1
+				{
+					(hi - lo) / (n - 1) as f64 / 2.
+				}
+				else
+				{
+					0.
+				};
+				values.extend([lo - pad, hi + pad]);
+			}
+			VoxelGrid(..) => (),
+		}
+		values
+			.into_iter()
+			.filter(|v| v.is_finite() && (!positive || *v > 0.))
+			.fold(None, |range, v| match range
+			{
+				Some((lo, hi)) => Some((v.min(lo), v.max(hi))),
+				None => Some((v, v)),
+			})
+	}
+
+	fn combined_options(&self, defaults: &[PlotOption<String>]) -> Vec<PlotOption<String>>
+	{
+		// The defaults come last, so that the options of the element take precedence.
+		self.options.iter().chain(defaults).cloned().collect()
+	}
+
+	/// Returns the point type and color of the outline drawn over filled points with a
+	/// `BorderColor`, if any.
+	fn point_edge(&self, options: &[PlotOption<String>]) -> Option<(i32, ColorType)>
+	{
+		if !matches!(self.plot_type, Points | LinesPoints)
+			|| options.iter().any(|o| matches!(o, VariablePointSymbol(_)))
+		{
+			return None;
+		}
+		let symbol = options.iter().find_map(|o| match o
+		{
+			PointSymbol(s) => Some(Some(*s)),
+			PointGlyph(_) => Some(None),
+			_ => None,
+		})??;
+		let hollow = match symbol
+		{
+			'S' | 'O' | 'T' | 'D' | 'R' => symbol.to_ascii_lowercase(),
+			_ => return None,
+		};
+		options.iter().find_map(|o| match o
+		{
+			BorderColor(c) =>
+			{
+				// The data has no column for the outline colors.
+				assert!(
+					!c.is_variable(),
+					"The outline of points cannot have a variable BorderColor"
+				);
+				Some((char_to_symbol(hollow), c.clone()))
+			}
+			_ => None,
+		})
+	}
+
+	/// The number of data columns read by the outline of filled points, see `point_edge`. The
+	/// variable colors, if any, are the last column and are not read.
+	fn point_edge_num_cols(&self, options: &[PlotOption<String>]) -> usize
+	{
+		if options
+			.iter()
+			.any(|o| matches!(o, Color(c) if c.is_variable()))
+		{
+			self.num_cols - 1
+		}
+		else
+		{
+			self.num_cols
+		}
+	}
+
+	fn write_args(
+		&self, source: &str, writer: &mut dyn Writer, version: GnuplotVersion,
+		defaults: &[PlotOption<String>], arrow_style_offset: usize,
+	)
+	{
+		let options = &self.combined_options(defaults);
+		match self.source_type
+		{
+			VoxelGrid(grid, _) =>
+			{
+				write!(writer, " {}", voxel_grid_name(grid));
+			}
+			Record =>
 			{
 				write!(
 					writer,
-					r#" "{}" binary endian=little array=({},{}) format="%float64" "#,
-					source, self.num_cols, self.num_rows
+					r#" "{}" binary endian=little record={} format="%float64" using "#,
+					source, self.num_rows
 				);
 
-				if let SizedArray(x1, y1, x2, y2) = self.source_type
+				let mut col_idx = 1;
+				while col_idx < self.num_cols + 1
 				{
-					let (x1, x2) = if x1 > x2 { (x2, x1) } else { (x1, x2) };
-
-					let (y1, y2) = if y1 > y2 { (y2, y1) } else { (y1, y2) };
-					write!(writer, "origin=({:.12e},{:.12e}", x1, y1);
-					if self.is_3d
-					{
-						write!(writer, ",0");
-					}
-					write!(writer, ") ");
-					if self.num_cols > 1
+					if col_idx == 5 && matches!(self.plot_type, LineSegments)
 					{
-						write!(
-							writer,
-							"dx={:.12e} ",
-							(x2 - x1) / (self.num_cols as f64 - 1.0)
-						);
+						// Segment style indices are local to the element.
+						write!(writer, "(${}+{})", col_idx, arrow_style_offset + 1);
 					}
 					else
 					{
-						write!(writer, "dx=1 ");
-					}
-					if self.num_rows > 1
-					{
-						write!(
-							writer,
-							"dy={:.12e} ",
-							(y2 - y1) / (self.num_rows as f64 - 1.0)
-						);
+						write!(writer, "{}", col_idx);
 					}
-					else
+					if col_idx < self.num_cols
 					{
-						write!(writer, "dy=1 ");
+						writer.write_str(":");
 					}
+					col_idx += 1;
 				}
 			}
+			Contours(..) =>
+			{
+				write!(writer, " {} using 1:2", source);
+			}
+			_ => self.write_array_source(source, self.is_3d, writer),
 		}
 
 		writer.write_str(" with ");
@@ -172,9 +423,23 @@ impl PlotElement
 			BoxErrorBars => "boxerrorbars",
 			Pm3D => "pm3d",
 			Image => "image",
+			Isosurface => "isosurface",
+			LineSegments => "vectors nohead arrowstyle variable",
 		};
 		writer.write_str(type_str);
 
+		if let VoxelGrid(_, level) = self.source_type
+		{
+			if let Isosurface = self.plot_type
+			{
+				write!(writer, " level {:.12e}", level);
+			}
+			else
+			{
+				write!(writer, " above {:.12e}", level);
+			}
+		}
+
 		if self.plot_type.is_fill()
 		{
 			if let FillBetween = self.plot_type
@@ -195,7 +460,7 @@ impl PlotElement
 			writer.write_str(" fill ");
 
 			let mut is_pattern = false;
-			first_opt! {self.options,
+			first_opt! {options,
 				FillPattern(pattern_opt) =>
 				{
 					is_pattern = true;
@@ -206,161 +471,880 @@ impl PlotElement
 					}
 				}
 			}
-
-			if !is_pattern
+
+			if !is_pattern
+			{
+				let mut color_has_alpha = false;
+				first_opt! {options,
+					Color(ref c) => {
+						color_has_alpha = c.has_alpha()
+					}
+				}
+				if !color_has_alpha
+				{
+					writer.write_str("transparent ");
+				}
+				writer.write_str("solid");
+				let mut alpha = 1.;
+				first_opt! {options,
+					FillAlpha(a) =>
+					{
+						alpha = a;
+					}
+				}
+				write!(writer, " {:.12e}", alpha);
+			}
+
+			if self.plot_type.is_line()
+			{
+				// Write this unconditionally so the write_line_options work below.
+				writer.write_str(" border ");
+				first_opt! {options,
+					BorderColor(ref s) =>
+					{
+						writer.write_str(&s.command());
+					}
+				}
+			}
+			else
+			{
+				writer.write_str(" noborder");
+			}
+		}
+
+		if self.plot_type.is_line()
+		{
+			AxesCommonData::write_line_options(writer, options, version);
+		}
+		if let Some((width, dash)) = self.segment_styles.first()
+		{
+			// The segments are drawn using their arrow styles, this only affects the legend sample.
+			AxesCommonData::write_line_options(
+				writer,
+				&[LineWidth(*width), LineStyle(dash.clone())],
+				version,
+			);
+		}
+
+		if self.plot_type.is_points()
+		{
+			// The variable options take precedence, as they add data columns that must be used.
+			if options.iter().any(|o| matches!(o, VariablePointSymbol(_)))
+			{
+				writer.write_str(" pt variable");
+			}
+			else
+			{
+				for o in options
+				{
+					match *o
+					{
+						PointSymbol(s) =>
+						{
+							write!(writer, " pt {}", char_to_symbol(s));
+							break;
+						}
+						PointGlyph(g) =>
+						{
+							write!(writer, " pt \"{}\"", escape(&g.to_string()));
+							break;
+						}
+						_ => (),
+					}
+				}
+			}
+
+			AxesCommonData::write_point_size_options(writer, options);
+		}
+
+		if let (VoxelGrid(..), Points) = (&self.source_type, &self.plot_type)
+		{
+			let mut has_color = false;
+			first_opt! {options,
+				Color(_) =>
+				{
+					has_color = true;
+				}
+			}
+			if !has_color
+			{
+				// Color the voxels by their value.
+				writer.write_str(" linecolor palette");
+			}
+		}
+		AxesCommonData::write_color_options(writer, options, self.plot_type.is_fill(), None);
+
+		writer.write_str(" t \"");
+		first_opt! {options,
+			Caption(ref s) =>
+			{
+				writer.write_str(&escape(s));
+			}
+		}
+		writer.write_str("\"");
+
+		first_opt! {options,
+			WhiskerBars(f) =>
+			{
+				write!(writer, " whiskerbars {}", f);
+			}
+		}
+
+		first_opt! {options,
+			Axes(x, y) =>
+			{
+				write!(writer, " axes {}{}",
+					match x
+					{
+						XAxis::X1 => "x1",
+						XAxis::X2 => "x2",
+					},
+					match y
+					{
+						YAxis::Y1 => "y1",
+						YAxis::Y2 => "y2",
+					}
+				);
+			}
+		}
+
+		if let Contours(..) = self.source_type
+		{
+			// Label the lines with their level, placing the labels like `set cntrlabel` does by
+			// default: every 20th point of each line, starting with the 5th.
+			write!(
+				writer,
+				r#", {} using 1:2:(sprintf("%g", $3)) every 20::4 with labels center"#,
+				source
+			);
+			first_opt! {options,
+				Color(ref c) =>
+				{
+					write!(writer, " textcolor {}", c.command());
+				}
+			}
+			writer.write_str(" t \"\"");
+		}
+
+		if let (Record, Some((hollow, edge_color))) = (&self.source_type, self.point_edge(options))
+		{
+			// Draw the outline of filled points on top of them, reusing the data but not the
+			// variable colors. Inline data is sent again without them, while a data file is read
+			// again skipping them.
+			let num_cols = self.point_edge_num_cols(options);
+			let format = if source == "-" || num_cols == self.num_cols
+			{
+				"%float64".to_string()
+			}
+			else
+			{
+				"%float64".repeat(num_cols) + "%*float64"
+			};
+			write!(
+				writer,
+				r#", "{}" binary endian=little record={} format="{}" using {} with points pt {}"#,
+				source,
+				self.num_rows,
+				format,
+				(1..=num_cols)
+					.map(|i| i.to_string())
+					.collect::<Vec<_>>()
+					.join(":"),
+				hollow
+			);
+			AxesCommonData::write_point_size_options(writer, options);
+			write!(writer, " linecolor {} t \"\"", edge_color.command());
+			first_opt! {options,
+				Axes(x, y) =>
+				{
+					write!(writer, " axes {}{}",
+						match x
+						{
+							XAxis::X1 => "x1",
+							XAxis::X2 => "x2",
+						},
+						match y
+						{
+							YAxis::Y1 => "y1",
+							YAxis::Y2 => "y2",
+						}
+					);
+				}
+			}
+		}
+	}
+
+	fn write_array_source(&self, source: &str, is_3d: bool, writer: &mut dyn Writer)
+	{
+		write!(
+			writer,
+			r#" "{}" binary endian=little array=({},{}) format="%float64" "#,
+			source, self.num_cols, self.num_rows
+		);
+
+		if let SizedArray(x1, y1, x2, y2) | Contours((x1, y1, x2, y2), _) = self.source_type
+		{
+			let (x1, x2) = if x1 > x2 { (x2, x1) } else { (x1, x2) };
+
+			let (y1, y2) = if y1 > y2 { (y2, y1) } else { (y1, y2) };
+			write!(writer, "origin=({:.12e},{:.12e}", x1, y1);
+			if is_3d
+			{
+				write!(writer, ",0");
+			}
+			write!(writer, ") ");
+			if self.num_cols > 1
+			{
+				write!(
+					writer,
+					"dx={:.12e} ",
+					(x2 - x1) / (self.num_cols as f64 - 1.0)
+				);
+			}
+			else
+			{
+				write!(writer, "dx=1 ");
+			}
+			if self.num_rows > 1
+			{
+				write!(
+					writer,
+					"dy={:.12e} ",
+					(y2 - y1) / (self.num_rows as f64 - 1.0)
+				);
+			}
+			else
+			{
+				write!(writer, "dy=1 ");
+			}
+		}
+	}
+
+	/// Traces the contour lines of this element's matrix into the datablock `name`, as `x y level`
+	/// rows with the lines separated by blank lines. The matrix is read from `source`, sending it
+	/// inline after the command if it is `"-"`.
+	fn write_contour_table(&self, name: &str, source: &str, writer: &mut dyn Writer)
+	{
+		let Contours(_, ref levels) = self.source_type
+		else
+		{
+			return;
+		};
+		let levels: Vec<_> = levels.iter().map(|l| format!("{:.12e}", l)).collect();
+		writer.write_str("set contour base\n");
+		writer.write_str("unset surface\n");
+		writer.write_str("set cntrparam linear\n");
+		writeln!(writer, "set cntrparam levels discrete {}", levels.join(","));
+		writeln!(writer, "set table {}", name);
+		writer.write_str("splot");
+		self.write_array_source(source, true, writer);
+		writer.write_str("with lines t \"\"\n");
+		if source == "-"
+		{
+			self.write_data(writer);
+		}
+		writer.write_str("unset table\n");
+		writer.write_str("unset contour\n");
+		writer.write_str("set surface\n");
+	}
+
+	/// Removes the caption of this element, hiding it from the legend
+	pub fn hide_caption(&mut self)
+	{
+		self.options.retain(|o| !matches!(o, Caption(_)));
+	}
+
+	pub fn is_boxes(&self) -> bool
+	{
+		matches!(self.plot_type, Boxes)
+	}
+
+	fn write_data(&self, writer: &mut dyn Writer)
+	{
+		for d in &self.data
+		{
+			writer.write_le_f64(*d);
+		}
+	}
+
+	/// Writes the first `num_cols` columns of the data
+	fn write_data_columns(&self, writer: &mut dyn Writer, num_cols: usize)
+	{
+		for row in self.data.chunks(self.num_cols)
+		{
+			for d in &row[..num_cols]
+			{
+				writer.write_le_f64(*d);
+			}
+		}
+	}
+}
+
+pub struct LabelData
+{
+	pub label_type: LabelType,
+	pub text: String,
+	pub options: Vec<LabelOption<String>>,
+}
+
+impl LabelData
+{
+	fn new(label_type: LabelType) -> Self
+	{
+		Self {
+			label_type,
+			text: "".into(),
+			options: vec![],
+		}
+	}
+
+	fn z_order(&self) -> i32
+	{
+		self.options
+			.iter()
+			.find_map(|o| match *o
+			{
+				LabelZOrder(z) => Some(z),
+				_ => None,
+			})
+			.unwrap_or(0)
+	}
+
+	pub fn set(&mut self, text: String, options: Vec<LabelOption<String>>)
+	{
+		self.text = text;
+		self.options = options;
+	}
+
+	pub fn write_out_commands(&self, writer: &mut dyn Writer)
+	{
+		self.write_out_commands_as(self.label_type, writer);
+	}
+
+	/// Writes out a label added with `AxesCommon::label` using a different tag, which determines
+	/// the drawing order.
+	pub fn write_out_commands_with_tag(&self, tag: i32, writer: &mut dyn Writer)
+	{
+		match self.label_type
+		{
+			Label(_, x, y) => self.write_out_commands_as(Label(tag, x, y), writer),
+			_ => self.write_out_commands(writer),
+		}
+	}
+
+	fn write_out_commands_as(&self, label_type: LabelType, writer: &mut dyn Writer)
+	{
+		let w = writer;
+		w.write_str("set ");
+
+		label_type.write_label_str(w);
+
+		w.write_str(" \"");
+		w.write_str(&escape(&self.text));
+		w.write_str("\"");
+
+		write_out_label_options(label_type, &self.options[..], w);
+
+		w.write_str("\n");
+	}
+
+	pub fn reset_state(&self, writer: &mut dyn Writer)
+	{
+		if let Label(tag, ..) = self.label_type
+		{
+			writeln!(writer, "unset label {}", tag);
+		}
+	}
+}
+
+pub struct LegendData
+{
+	pub x: Coordinate,
+	pub y: Coordinate,
+	pub legend_options: Vec<LegendOption<String>>,
+	pub text_options: Vec<LabelOption<String>>,
+}
+
+impl LegendData
+{
+	pub fn write_out(&self, writer: &mut dyn Writer)
+	{
+		let w = writer;
+		write!(w, "set key at {},{}", self.x, self.y);
+
+		first_opt_default! {self.legend_options,
+			Placement(h, v) =>
+			{
+				w.write_str(match h
+				{
+					AlignLeft => " left",
+					AlignRight => " right",
+					_ => " center"
+				});
+				w.write_str(match v
+				{
+					AlignTop => " top",
+					AlignBottom => " bottom",
+					_ => " center"
+				});
+			},
+			_ =>
+			{
+				w.write_str(" right top");
+			}
+		}
+
+		first_opt_default! {self.legend_options,
+			Horizontal =>
+			{
+				w.write_str(" horizontal");
+			},
+			_ =>
+			{
+				w.write_str(" vertical");
+			}
+		}
+
+		first_opt_default! {self.legend_options,
+			Reverse =>
+			{
+				w.write_str(" reverse");
+			},
+			_ =>
+			{
+				w.write_str(" noreverse");
+			}
+		}
+
+		first_opt_default! {self.legend_options,
+			Invert =>
+			{
+				w.write_str(" invert");
+			},
+			_ =>
+			{
+				w.write_str(" noinvert");
+			}
+		}
+
+		first_opt! {self.legend_options,
+			Title(ref s) =>
+			{
+				w.write_str(" title \"");
+				w.write_str(&escape(s));
+				w.write_str("\"");
+			}
+		}
+
+		first_opt! {self.text_options,
+			Font(ref f, s) =>
+			{
+				w.write_str(" font \"");
+				w.write_str(&escape(f));
+				w.write_str(",");
+				w.write_str(&s.to_string()[..]);
+				w.write_str("\"");
+			}
+		}
+		first_opt! {self.text_options,
+			TextColor(ref s) =>
+			{
+				write!(w, " textcolor {} ", s.command());
+			}
+		}
+		first_opt! {self.text_options,
+			TextAlign(a) =>
+			{
+				w.write_str(match a
+				{
+					AlignLeft => " Left",
+					AlignRight => " Right",
+					_ => ""
+				});
+			}
+		}
+
+		first_opt! {self.legend_options,
+			MaxRows(r) =>
+			{
+				write!(w, " maxrows {}", r as i32);
+			}
+		}
+
+		first_opt! {self.legend_options,
+			MaxCols(l) =>
+			{
+				write!(w, " maxcols {}", l as i32);
+			}
+		}
+
+		w.write_str("\n");
+	}
+
+	pub fn reset_state(&self, writer: &mut dyn Writer)
+	{
+		writer.write_str("unset key\n");
+	}
+}
+
+pub struct ColorBoxData
+{
+	pub show: bool,
+	pub options: Vec<ColorBoxOption>,
+}
+
+impl ColorBoxData
+{
+	pub fn write_out(&self, writer: &mut dyn Writer)
+	{
+		let w = writer;
+		if !self.show
+		{
+			w.write_str("unset colorbox\n");
+			return;
+		}
+
+		let mut horizontal = false;
+		first_opt! {self.options,
+			ColorBoxHorizontal =>
+			{
+				horizontal = true;
+			}
+		}
+		w.write_str(
+			if horizontal
+			{
+				"set colorbox horizontal"
+			}
+			else
+			{
+				"set colorbox vertical"
+			},
+		);
+
+		let inside = if horizontal
+		{
+			(0.1, 0.04, 0.8, 0.04)
+		}
+		else
+		{
+			(0.92, 0.1, 0.04, 0.8)
+		};
+		let placement = self.options.iter().find_map(|o| match *o
+		{
+			ColorBoxInside => Some(("graph", inside)),
+			ColorBoxScreen(x, y, width, height) => Some(("screen", (x, y, width, height))),
+			ColorBoxGraph(x, y, width, height) => Some(("graph", (x, y, width, height))),
+			_ => None,
+		});
+		if let Some((system, (x, y, width, height))) = placement
+		{
+			write!(
+				w,
+				" user origin {system} {x:.12e}, {system} {y:.12e} size {system} {width:.12e}, {system} {height:.12e}"
+			);
+		}
+		else
+		{
+			w.write_str(" default");
+		}
+
+		first_opt_default! {self.options,
+			ColorBoxBorder(b) =>
+			{
+				w.write_str(if b { " bdefault" } else { " noborder" });
+			},
+			_ =>
+			{
+				w.write_str(" bdefault");
+			}
+		}
+
+		first_opt_default! {self.options,
+			ColorBoxFront(b) =>
+			{
+				w.write_str(if b { " front" } else { " back" });
+			},
+			_ =>
+			{
+				w.write_str(" front");
+			}
+		}
+
+		w.write_str("\n");
+	}
+
+	pub fn reset_state(&self, writer: &mut dyn Writer)
+	{
+		writer.write_str("set colorbox default vertical bdefault front\n");
+	}
+}
+
+pub struct ArrowData
+{
+	/// Coordinates of the start, one per axis.
+	pub from: Vec<Coordinate>,
+	/// Coordinates of the end, one per axis.
+	pub to: Vec<Coordinate>,
+	pub plot_options: Vec<PlotOption<String>>,
+}
+
+impl ArrowData
+{
+	pub fn write_out(&self, tag: i32, writer: &mut dyn Writer)
+	{
+		let join = |coords: &[Coordinate]| {
+			coords
+				.iter()
+				.map(|c| c.to_string())
+				.collect::<Vec<_>>()
+				.join(",")
+		};
+
+		let w = writer;
+		write!(
+			w,
+			"set arrow {} from {} to {}",
+			tag,
+			join(&self.from),
+			join(&self.to)
+		);
+
+		first_opt! {self.plot_options,
+			ArrowType(s) =>
+			{
+				w.write_str(match s
+				{
+					Open => "",
+					Closed => " empty",
+					Filled => " filled",
+					NoArrow => " nohead",
+				});
+			}
+		}
+
+		w.write_str(" size graph ");
+		first_opt_default! {self.plot_options,
+			ArrowSize(z) =>
+			{
+				write!(w, "{:.12e}", z);
+			},
+			_ =>
+			{
+				w.write_str("0.05");
+			}
+		}
+		w.write_str(",12");
+
+		first_opt! {self.plot_options,
+			Layer(l) =>
+			{
+				write!(w, " {}", l.front_or_back_str());
+			}
+		}
+
+		AxesCommonData::write_color_options(w, &self.plot_options, false, Some(ColorType::Black));
+		AxesCommonData::write_line_options(
+			w,
+			&self.plot_options,
+			GnuplotVersion { major: 0, minor: 0 },
+		);
+
+		w.write_str("\n");
+	}
+
+	pub fn reset_state(&self, tag: i32, writer: &mut dyn Writer)
+	{
+		writeln!(writer, "unset arrow {}", tag);
+	}
+
+	pub fn z_order(&self) -> i32
+	{
+		z_order(&self.plot_options)
+	}
+}
+
+/// A geometric shape drawn via `set object`
+pub enum ObjectShape
+{
+	/// Opposite corners
+	Rectangle(Coordinate, Coordinate, Coordinate, Coordinate),
+	/// Center and radius. The radius is measured along the X axis.
+	Circle(Coordinate, Coordinate, Coordinate),
+	/// Center, width, height and the angle (in degrees) of the width axis
+	Ellipse(Coordinate, Coordinate, Coordinate, Coordinate, f64),
+	/// Vertices, the polygon is closed automatically
+	Polygon(Vec<(Coordinate, Coordinate)>),
+}
+
+pub struct ObjectData
+{
+	pub shape: ObjectShape,
+	pub plot_options: Vec<PlotOption<String>>,
+}
+
+impl ObjectData
+{
+	pub fn write_out(&self, tag: i32, writer: &mut dyn Writer, version: GnuplotVersion)
+	{
+		let w = writer;
+		write!(w, "set object {} ", tag);
+		match self.shape
+		{
+			ObjectShape::Rectangle(x1, y1, x2, y2) =>
+			{
+				write!(w, "rectangle from {},{} to {},{}", x1, y1, x2, y2);
+			}
+			ObjectShape::Circle(x, y, r) =>
 			{
-				let mut color_has_alpha = false;
-				first_opt! {self.options,
-					Color(ref c) => {
-						color_has_alpha = c.has_alpha()
-					}
-				}
-				if !color_has_alpha
+				write!(w, "circle at {},{} size {}", x, y, r);
+			}
+			ObjectShape::Ellipse(x, y, width, height, angle) =>
+			{
+				write!(
+					w,
+					"ellipse at {},{} size {},{} angle {:.12e}",
+					x, y, width, height, angle
+				);
+			}
+			ObjectShape::Polygon(ref vertices) =>
+			{
+				// `Axes2D::polygon_object` checks that there is at least one vertex.
+				w.write_str("polygon");
+				for (i, &(x, y)) in vertices.iter().enumerate()
 				{
-					writer.write_str("transparent ");
-				}
-				writer.write_str("solid");
-				let mut alpha = 1.;
-				first_opt! {self.options,
-					FillAlpha(a) =>
-					{
-						alpha = a;
-					}
+					write!(w, " {} {},{}", if i == 0 { "from" } else { "to" }, x, y);
 				}
-				write!(writer, " {:.12e}", alpha);
+				let (x, y) = vertices[0];
+				write!(w, " to {},{}", x, y);
 			}
+		}
 
-			if self.plot_type.is_line()
+		first_opt_default! {self.plot_options,
+			Layer(l) =>
 			{
-				// Write this unconditionally so the write_line_options work below.
-				writer.write_str(" border ");
-				first_opt! {self.options,
-					BorderColor(ref s) =>
-					{
-						writer.write_str(&s.command());
-					}
-				}
-			}
-			else
+				write!(w, " {}", l.to_str());
+			},
+			_ =>
 			{
-				writer.write_str(" noborder");
+				w.write_str(" back");
 			}
 		}
 
-		if self.plot_type.is_line()
-		{
-			AxesCommonData::write_line_options(writer, options, version);
+		first_opt_default! {self.plot_options,
+			Clip(c) =>
+			{
+				w.write_str(if c { " clip" } else { " noclip" });
+			},
+			_ =>
+			{
+				w.write_str(" clip");
+			}
 		}
 
-		if self.plot_type.is_points()
-		{
-			first_opt! {self.options,
-				PointSymbol(s) =>
-				{
-					write!(writer, " pt {}", char_to_symbol(s));
-				}
-			}
+		AxesCommonData::write_color_options(w, &self.plot_options, true, None);
 
-			first_opt! {self.options,
-				PointSize(z) =>
+		w.write_str(" fillstyle ");
+		let mut is_pattern = false;
+		first_opt! {self.plot_options,
+			FillPattern(pattern_opt) =>
+			{
+				is_pattern = true;
+				w.write_str("pattern");
+				if let Fix(val) = pattern_opt
 				{
-					write!(writer, " ps {}", z);
+					write!(w, " {}", val as i32);
 				}
 			}
 		}
-
-		AxesCommonData::write_color_options(writer, &self.options, self.plot_type.is_fill(), None);
-
-		writer.write_str(" t \"");
-		first_opt! {self.options,
-			Caption(ref s) =>
+		let has_color = self.plot_options.iter().any(|o| matches!(o, Color(_)));
+		if !is_pattern
+		{
+			if has_color
 			{
-				writer.write_str(&escape(s));
+				let mut alpha = 1.;
+				first_opt! {self.plot_options,
+					FillAlpha(a) =>
+					{
+						alpha = a;
+					}
+				}
+				write!(w, "transparent solid {:.12e}", alpha);
 			}
-		}
-		writer.write_str("\"");
-
-		first_opt! {self.options,
-			WhiskerBars(f) =>
+			else
 			{
-				write!(writer, " whiskerbars {}", f);
+				// Gnuplot would fill the shape with the background color, hiding what's behind it.
+				w.write_str("empty");
 			}
 		}
 
-		first_opt! {self.options,
-			Axes(x, y) =>
+		let mut has_border = false;
+		first_opt! {self.plot_options,
+			BorderColor(ref s) =>
 			{
-				write!(writer, " axes {}{}",
-					match x
-					{
-						XAxis::X1 => "x1",
-						XAxis::X2 => "x2",
-					},
-					match y
-					{
-						YAxis::Y1 => "y1",
-						YAxis::Y2 => "y2",
-					}
-				);
+				has_border = true;
+				write!(w, " border lc {}", s.command());
 			}
 		}
+		if !has_border && !has_color && !is_pattern
+		{
+			// Draw the outline of shapes that are not filled, so they are visible.
+			has_border = true;
+			w.write_str(" border");
+		}
+		if has_border
+		{
+			AxesCommonData::write_line_options(w, &self.plot_options, version);
+		}
+		else
+		{
+			w.write_str(" noborder");
+		}
+
+		w.write_str("\n");
 	}
 
-	fn write_data(&self, writer: &mut dyn Writer)
+	pub fn reset_state(&self, tag: i32, writer: &mut dyn Writer)
 	{
-		for d in &self.data
-		{
-			writer.write_le_f64(*d);
-		}
+		writeln!(writer, "unset object {}", tag);
+	}
+
+	pub fn z_order(&self) -> i32
+	{
+		z_order(&self.plot_options)
 	}
 }
 
-pub struct LabelData
+#[derive(Clone)]
+pub struct BorderOptions
 {
-	pub label_type: LabelType,
-	pub text: String,
-	pub options: Vec<LabelOption<String>>,
+	pub front: bool,
+	/// Bitmask of the border locations to draw.
+	pub locations: i32,
+	pub options: Vec<PlotOption<String>>,
 }
 
-impl LabelData
+impl BorderOptions
 {
-	fn new(label_type: LabelType) -> Self
+	pub fn new(locations: i32) -> BorderOptions
 	{
-		Self {
-			label_type,
-			text: "".into(),
+		BorderOptions {
+			front: true,
+			locations,
 			options: vec![],
 		}
 	}
 
-	pub fn set(&mut self, text: String, options: Vec<LabelOption<String>>)
-	{
-		self.text = text;
-		self.options = options;
-	}
-
-	pub fn write_out_commands(&self, writer: &mut dyn Writer)
+	pub fn write_out(&self, writer: &mut dyn Writer, version: GnuplotVersion)
 	{
-		let w = writer;
-		w.write_str("set ");
-
-		self.label_type.write_label_str(w);
-
-		w.write_str(" \"");
-		w.write_str(&escape(&self.text));
-		w.write_str("\"");
-
-		write_out_label_options(self.label_type, &self.options[..], w);
+		writer.write_str("set border ");
+		write!(writer, "{}", self.locations);
+		writer.write_str(if self.front { " front " } else { " back " });
 
-		w.write_str("\n");
-	}
+		AxesCommonData::write_color_options(writer, &self.options, false, Some(ColorType::Black));
+		AxesCommonData::write_line_options(writer, &self.options, version);
 
-	pub fn reset_state(&self, writer: &mut dyn Writer)
-	{
-		if let Label(tag, ..) = self.label_type
-		{
-			writeln!(writer, "unset label {}", tag);
-		}
+		writer.write_str("\n");
 	}
 }
 
@@ -446,7 +1430,17 @@ pub fn write_out_label_options(
 	let w = writer;
 	if let Label(_, x, y) = label_type
 	{
-		write!(w, " at {},{} front", x, y);
+		write!(w, " at {},{}", x, y);
+		first_opt_default! {options,
+			LabelLayer(l) =>
+			{
+				write!(w, " {}", l.front_or_back_str());
+			},
+			_ =>
+			{
+				w.write_str(" front");
+			}
+		}
 	}
 
 	first_opt! {options,
@@ -603,6 +1597,9 @@ pub enum PlotType
 	BoxXYError,
 	Pm3D,
 	Image,
+	Isosurface,
+	/// Lines with variable width or dash type, see `PlotElement::new_line_segments`.
+	LineSegments,
 }
 
 impl PlotType
@@ -638,7 +1635,7 @@ impl PlotType
 	{
 		matches!(
 			*self,
-			Boxes | FillBetween | BoxAndWhisker | BoxXYError | BoxErrorBars | Polygons
+			Boxes | FillBetween | BoxAndWhisker | BoxXYError | BoxErrorBars | Polygons | Isosurface
 		)
 	}
 }
@@ -648,6 +1645,9 @@ pub enum TickType
 	None,
 	Custom(Vec<Tick<f64, String>>),
 	Auto(AutoOption<f64>, u32),
+	/// Computed from the range of the axis when writing if it is fixed (see `time_ticks`), otherwise
+	/// placed by gnuplot.
+	Time(TimeTicks, Option<String>),
 }
 
 pub struct AxisData
@@ -690,7 +1690,9 @@ impl AxisData
 		}
 	}
 
-	pub fn write_out_commands(&self, w: &mut dyn Writer, version: GnuplotVersion)
+	pub fn write_out_commands(
+		&self, w: &mut dyn Writer, version: GnuplotVersion, shared: Option<SharedAxis>,
+	)
 	{
 		if self.axis != TickAxis::CB
 		{
@@ -766,16 +1768,24 @@ impl AxisData
 		}
 		w.write_str("\n");
 
+		// The ends left on Auto show the union of the ranges of the shared axes.
+		let (min, max) = match (self.min, self.max, shared.and_then(|s| s.range))
+		{
+			(Auto, Auto, Some((lo, hi))) => (Fix(lo), Fix(hi)),
+			(Auto, max, Some((lo, _))) => (Fix(lo), max),
+			(min, Auto, Some((_, hi))) => (min, Fix(hi)),
+			(min, max, _) => (min, max),
+		};
 		w.write_str("set ");
 		w.write_str(self.axis.get_range_str());
 		w.write_str(" [");
-		match self.min
+		match min
 		{
 			Fix(v) => write!(w, "{:.12e}", v),
 			Auto => w.write_str("*"),
 		};
 		w.write_str(":");
-		match self.max
+		match max
 		{
 			Fix(v) => write!(w, "{:.12e}", v),
 			Auto => w.write_str("*"),
@@ -790,7 +1800,26 @@ impl AxisData
 		}
 
 		let mut write_tick_options = true;
-		match self.tick_type
+		let mut time_format = None;
+		let mut default_time_format = None;
+		let resolved_ticks;
+		let tick_type = match (&self.tick_type, min, max)
+		{
+			(&TickType::Time(ticks, ref format), Fix(min), Fix(max)) =>
+			{
+				resolved_ticks = TickType::Custom(time_ticks(min, max, ticks, format.as_deref()));
+				&resolved_ticks
+			}
+			(&TickType::Time(ticks, ref format), _, _) =>
+			{
+				// The range is only known after autoscaling, so gnuplot places the ticks.
+				time_format = format.as_deref();
+				default_time_format = ticks.unbounded_format();
+				&self.tick_type
+			}
+			(tick_type, _, _) => tick_type,
+		};
+		match *tick_type
 		{
 			TickType::None =>
 			{
@@ -858,6 +1887,12 @@ impl AxisData
 				}
 				w.write_str(")");
 			}
+			TickType::Time(ticks, _) =>
+			{
+				w.write_str("set ");
+				w.write_str(self.axis.get_tick_str());
+				w.write_str(&ticks.gnuplot_spacing());
+			}
 		}
 
 		if write_tick_options
@@ -919,10 +1954,28 @@ impl AxisData
 
 			write!(w, " scale {:.12e},{:.12e}", major_scale, minor_scale);
 
-			first_opt! {tick_options,
-				Format(ref f) =>
-				{
-					write!(w, r#" format "{}""#, f);
+			if shared.is_some_and(|s| s.hide_tick_labels)
+			{
+				w.write_str(r#" format """#);
+			}
+			else if let Some(f) = time_format
+			{
+				write!(w, r#" format "{}""#, f);
+			}
+			else
+			{
+				first_opt_default! {tick_options,
+					Format(ref f) =>
+					{
+						write!(w, r#" format "{}""#, f);
+					},
+					_ =>
+					{
+						if let Some(f) = default_time_format
+						{
+							write!(w, r#" format "{}""#, f);
+						}
+					}
 				}
 			}
 		}
@@ -931,6 +1984,15 @@ impl AxisData
 		w.write_str("\n");
 	}
 
+	pub fn reset_state(&self, w: &mut dyn Writer, shared: Option<SharedAxis>)
+	{
+		if shared.is_some_and(|s| s.hide_tick_labels)
+			|| matches!(self.tick_type, TickType::Time(..))
+		{
+			writeln!(w, "set format {}", self.axis.get_axis_str());
+		}
+	}
+
 	pub fn set_ticks_custom<T: DataType, TL: IntoIterator<Item = Tick<T, String>>>(
 		&mut self, ticks: TL, tick_options: Vec<TickOption<String>>,
 		label_options: Vec<LabelOption<String>>,
@@ -988,46 +2050,235 @@ impl AxisData
 		self.grid = show;
 	}
 
-	pub fn set_minor_grid(&mut self, show: bool)
+	pub fn set_minor_grid(&mut self, show: bool)
+	{
+		self.mgrid = show;
+	}
+
+	pub fn set_time(&mut self, is_time: bool)
+	{
+		self.is_time = is_time;
+	}
+}
+
+pub fn z_order(options: &[PlotOption<String>]) -> i32
+{
+	options
+		.iter()
+		.find_map(|o| match *o
+		{
+			ZOrder(z) => Some(z),
+			_ => None,
+		})
+		.unwrap_or(0)
+}
+
+/// Returns the items (labels, arrows, shapes) sorted by their z-order, with ties broken by the
+/// insertion order, together with the tags to write them out with, as gnuplot draws them in the
+/// order of their tags.
+pub fn in_z_order<T>(items: &[T], z_order: impl Fn(&T) -> i32) -> impl Iterator<Item = (i32, &T)>
+{
+	let mut sorted: Vec<_> = items.iter().collect();
+	sorted.sort_by_key(|item| z_order(item));
+	sorted
+		.into_iter()
+		.enumerate()
+		.map(|(i, item)| (i as i32 + 1, item))
+}
+
+/// Writes data into a file in the data directory, returning the escaped name of the file for use in
+/// a command. Returns `"-"` if there is no data directory or the file cannot be created, in which
+/// case the data is sent inline after the command instead.
+pub fn write_data_file(
+	data_directory: Option<&str>, name: &str, write: impl FnOnce(&mut dyn Writer),
+) -> String
+{
+	let Some(data_directory) = data_directory
+	else
+	{
+		return "-".into();
+	};
+	let filename = path::Path::new(data_directory).join(name);
+	match (filename.to_str(), fs::File::create(&filename))
+	{
+		(Some(name), Ok(mut file)) =>
+		{
+			write(&mut file);
+			escape(name)
+		}
+		_ => "-".into(),
+	}
+}
+
+pub fn contour_table_name(elem: usize) -> String
+{
+	format!("$contours{}", elem)
+}
+
+pub fn voxel_grid_name(grid: usize) -> String
+{
+	format!("$vgrid{}", grid)
+}
+
+pub fn char_to_symbol(c: char) -> i32
+{
+	match c
+	{
+		'.' => 0,
+		'+' => 1,
+		'x' => 2,
+		'*' => 3,
+		's' => 4,
+		'S' => 5,
+		'o' => 6,
+		'O' => 7,
+		't' => 8,
+		'T' => 9,
+		'd' => 10,
+		'D' => 11,
+		'r' => 12,
+		'R' => 13,
+		a => panic!("Invalid symbol {}", a),
+	}
+}
+
+enum DataSourceType
+{
+	Record,
+	Array,
+	SizedArray(f64, f64, f64, f64),
+	/// A voxel grid with the given index, and the isosurface level or point threshold.
+	VoxelGrid(usize, f64),
+	/// Contour lines at the levels of a matrix spanning the coordinates, traced by gnuplot into a
+	/// datablock before plotting (see `contour_table_name`).
+	Contours((f64, f64, f64, f64), Vec<f64>),
+}
+
+/// Screen area covered by panels laid out by the figure: left, right, bottom and top
+pub(crate) const PANEL_AREA: (f32, f32, f32, f32) = (0.1, 0.95, 0.1, 0.92);
+
+/// A grid layout for placing axes on a figure, with rows and columns of varying sizes. Axes can span
+/// multiple cells. Place axes into the grid with `AxesCommon::set_grid_spec_cell`.
+///
+/// All the dimensions are in screen coordinates, ranging from 0 to 1. The cells specify the plot
+/// area of the axes, so the outer margins and the spacing need to leave room for the tick labels.
+#[derive(Clone, Debug)]
+pub struct GridSpec
+{
+	width_ratios: Vec<f64>,
+	height_ratios: Vec<f64>,
+	spacing: (f64, f64),
+	margins: (f64, f64, f64, f64),
+}
+
+impl GridSpec
+{
+	/// Creates a new grid with equally sized cells.
+	/// # Arguments
+	/// * `rows` - Number of rows. Must be greater than 0.
+	/// * `columns` - Number of columns. Must be greater than 0.
+	pub fn new(rows: usize, columns: usize) -> GridSpec
+	{
+		assert!(
+			rows > 0 && columns > 0,
+			"A grid needs at least one row and one column, got {}x{}",
+			rows,
+			columns
+		);
+		let (left, right, bottom, top) = PANEL_AREA;
+		GridSpec {
+			width_ratios: vec![1.0; columns],
+			height_ratios: vec![1.0; rows],
+			spacing: (0.1, 0.1),
+			margins: (left as f64, right as f64, bottom as f64, top as f64),
+		}
+	}
+
+	/// Sets the relative widths of the columns, from left to right. Must have one positive value per column.
+	pub fn set_width_ratios(&mut self, ratios: &[f64]) -> &mut Self
+	{
+		assert_eq!(
+			ratios.len(),
+			self.width_ratios.len(),
+			"Expected one width ratio per column"
+		);
+		assert!(
+			ratios.iter().all(|&r| r > 0.0),
+			"The width ratios must be positive, got {:?}",
+			ratios
+		);
+		self.width_ratios = ratios.to_vec();
+		self
+	}
+
+	/// Sets the relative heights of the rows, from top to bottom. Must have one positive value per row.
+	pub fn set_height_ratios(&mut self, ratios: &[f64]) -> &mut Self
+	{
+		assert_eq!(
+			ratios.len(),
+			self.height_ratios.len(),
+			"Expected one height ratio per row"
+		);
+		assert!(
+			ratios.iter().all(|&r| r > 0.0),
+			"The height ratios must be positive, got {:?}",
+			ratios
+		);
+		self.height_ratios = ratios.to_vec();
+		self
+	}
+
+	/// Sets the space between the cells.
+	/// # Arguments
+	/// * `horizontal` - Space between adjacent columns
+	/// * `vertical` - Space between adjacent rows
+	pub fn set_spacing(&mut self, horizontal: f64, vertical: f64) -> &mut Self
 	{
-		self.mgrid = show;
+		self.spacing = (horizontal, vertical);
+		self
 	}
 
-	pub fn set_time(&mut self, is_time: bool)
+	/// Sets the location of the outer edges of the grid.
+	/// # Arguments
+	/// * `left` - Left edge of the left-most column
+	/// * `right` - Right edge of the right-most column
+	/// * `bottom` - Bottom edge of the bottom row
+	/// * `top` - Top edge of the top row
+	pub fn set_margins(&mut self, left: f64, right: f64, bottom: f64, top: f64) -> &mut Self
 	{
-		self.is_time = is_time;
+		self.margins = (left, right, bottom, top);
+		self
 	}
-}
 
-pub fn char_to_symbol(c: char) -> i32
-{
-	match c
+	/// Returns the `(left, bottom, right, top)` screen coordinates of the area spanned by the cells.
+	/// # Arguments
+	/// * `rows` - Rows spanned by the area, counting from the top, starting at 0
+	/// * `columns` - Columns spanned by the area, counting from the left, starting at 0
+	pub fn cell(&self, rows: Range<usize>, columns: Range<usize>) -> (f64, f64, f64, f64)
 	{
-		'.' => 0,
-		'+' => 1,
-		'x' => 2,
-		'*' => 3,
-		's' => 4,
-		'S' => 5,
-		'o' => 6,
-		'O' => 7,
-		't' => 8,
-		'T' => 9,
-		'd' => 10,
-		'D' => 11,
-		'r' => 12,
-		'R' => 13,
-		a => panic!("Invalid symbol {}", a),
+		let (left, right, bottom, top) = self.margins;
+		let (spacing_x, spacing_y) = self.spacing;
+		let (x1, x2) = span(&self.width_ratios, columns, right - left, spacing_x);
+		let (y1, y2) = span(&self.height_ratios, rows, top - bottom, spacing_y);
+		(left + x1, top - y2, left + x2, top - y1)
 	}
 }
 
-enum DataSourceType
+/// Returns the start and end offsets of the span of cells along one dimension of a grid.
+fn span(ratios: &[f64], cells: Range<usize>, length: f64, spacing: f64) -> (f64, f64)
 {
-	Record,
-	Array,
-	SizedArray(f64, f64, f64, f64),
+	assert!(
+		cells.start < cells.end && cells.end <= ratios.len(),
+		"Invalid span of cells {:?}, the grid has {} cells along this dimension",
+		cells,
+		ratios.len()
+	);
+	let scale = (length - spacing * (ratios.len() - 1) as f64) / ratios.iter().sum::<f64>();
+	let offset = |n: usize| ratios[..n].iter().sum::<f64>() * scale + n as f64 * spacing;
+	(offset(cells.start), offset(cells.end) - spacing)
 }
 
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct Margins
 {
 	pub left: Option<f32>,
@@ -1036,14 +2287,6 @@ pub struct Margins
 	pub bottom: Option<f32>,
 }
 
-impl Default for Margins
-{
-	fn default() -> Self
-	{
-		Self::new()
-	}
-}
-
 impl Margins
 {
 	pub fn new() -> Self
@@ -1056,6 +2299,17 @@ impl Margins
 		}
 	}
 
+	/// Returns these margins, with the unset ones taken from `defaults`
+	pub fn or(&self, defaults: &Margins) -> Margins
+	{
+		Margins {
+			left: self.left.or(defaults.left),
+			right: self.right.or(defaults.right),
+			top: self.top.or(defaults.top),
+			bottom: self.bottom.or(defaults.bottom),
+		}
+	}
+
 	pub fn write_out_commands(&self, w: &mut dyn Writer)
 	{
 		let mut write_margin = |margin, v| {
@@ -1074,6 +2328,28 @@ impl Margins
 	}
 }
 
+/// An axis shared with other axes of a figure
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SharedAxis
+{
+	/// Union of the ranges of the axes sharing this axis, used for the ends of this axis left on
+	/// `Auto`. This is `None` if none of them has a known range.
+	pub range: Option<(f64, f64)>,
+	pub hide_tick_labels: bool,
+}
+
+/// How the figure lays out an axes with shared axes. This is resolved when the figure is written,
+/// so that it doesn't depend on the order of the calls that set up the figure.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SharedLayout
+{
+	pub x: Option<SharedAxis>,
+	pub y: Option<SharedAxis>,
+	/// Margins aligning the axes to the cell of the multiplot layout it is in. The margins set by
+	/// the user take precedence.
+	pub margins: Margins,
+}
+
 #[derive(Copy, Clone)]
 pub struct Position
 {
@@ -1092,7 +2368,7 @@ pub struct AxesCommonData
 {
 	pub grid_options: Vec<PlotOption<String>>,
 	pub minor_grid_options: Vec<PlotOption<String>>,
-	pub grid_front: bool,
+	pub grid_layer: LayerType,
 	pub elems: Vec<PlotElement>,
 	pub x_axis: AxisData,
 	pub x2_axis: AxisData,
@@ -1107,7 +2383,11 @@ pub struct AxesCommonData
 	pub margins: Margins,
 	pub palette: PaletteType<Vec<(f32, f32, f32, f32)>>,
 	pub colormaps: Vec<(String, PaletteType<Vec<(f32, f32, f32, f32)>>)>,
+	pub palette_max_colors: Option<u32>,
 	pub box_width: Option<(f64, bool)>,
+	pub color_box: Option<ColorBoxData>,
+	pub style_cycle: Option<StyleCycle>,
+	pub theme: Option<Theme>,
 }
 
 impl AxesCommonData
@@ -1117,7 +2397,7 @@ impl AxesCommonData
 		let mut ret = AxesCommonData {
 			grid_options: vec![],
 			minor_grid_options: vec![],
-			grid_front: false,
+			grid_layer: Back,
 			elems: Vec::new(),
 			x_axis: AxisData::new(TickAxis::X),
 			y_axis: AxisData::new(TickAxis::Y),
@@ -1132,15 +2412,79 @@ impl AxesCommonData
 			margins: Margins::new(),
 			palette: COLOR.to_one_way_owned(),
 			colormaps: Vec::new(),
+			palette_max_colors: None,
 			box_width: None,
+			color_box: None,
+			style_cycle: None,
+			theme: None,
 		};
 		ret.x2_axis.tick_type = TickType::None;
 		ret.y2_axis.tick_type = TickType::None;
 		ret
 	}
 
+	pub fn set_time_ticks(
+		&mut self, axis: TickAxis, ticks: TimeTicks, format: Option<&str>,
+		tick_options: &[TickOption<&str>], label_options: &[LabelOption<&str>],
+	)
+	{
+		let axis = match axis
+		{
+			TickAxis::X => &mut self.x_axis,
+			TickAxis::Y => &mut self.y_axis,
+			TickAxis::X2 => &mut self.x2_axis,
+			TickAxis::Y2 => &mut self.y2_axis,
+			TickAxis::CB => &mut self.cb_axis,
+			TickAxis::Z => panic!("Time ticks are not supported on the Z axis"),
+		};
+		axis.is_time = true;
+		axis.tick_type = TickType::Time(ticks, format.map(|f| f.into()));
+		axis.tick_options = tick_options.to_one_way_owned();
+		axis.label_options = label_options.to_one_way_owned();
+	}
+
+	/// Returns the border to draw, combining the border configured on the axes (if any) with the
+	/// theme. Returns `None` if neither configure it.
+	pub fn themed_border(
+		&self, border: Option<&BorderOptions>, default_locations: i32,
+	) -> Option<BorderOptions>
+	{
+		let theme = self.theme.as_ref();
+		let mut border = match (border, theme)
+		{
+			(Some(border), _) => border.clone(),
+			(None, Some(theme)) => BorderOptions::new(
+				if theme.show_border() == Some(false)
+				{
+					0
+				}
+				else
+				{
+					default_locations
+				},
+			),
+			(None, None) => return None,
+		};
+		if let Some(theme) = theme
+		{
+			border.options.extend_from_slice(theme.border_options());
+		}
+		Some(border)
+	}
+
 	pub fn write_grid_options(&self, c: &mut dyn Writer, axes: &[TickAxis], version: GnuplotVersion)
 	{
+		let theme_axes = [TickAxis::X, TickAxis::Y];
+		let mut axes = axes;
+		let mut grid_options = self.grid_options.clone();
+		if let Some(theme) = self.theme.as_ref()
+		{
+			if axes.is_empty() && theme.show_grid()
+			{
+				axes = &theme_axes;
+			}
+			grid_options.extend_from_slice(theme.grid_options());
+		}
 		if !axes.is_empty()
 		{
 			c.write_str("set grid ");
@@ -1158,17 +2502,10 @@ impl AxesCommonData
 				}
 			}
 
-			if self.grid_front
-			{
-				c.write_str("front ");
-			}
-			else
-			{
-				c.write_str("back ");
-			}
+			write!(c, "{} ", self.grid_layer.front_or_back_str());
 
-			AxesCommonData::write_line_options(c, &self.grid_options, version);
-			AxesCommonData::write_color_options(c, &self.grid_options, false, None);
+			AxesCommonData::write_line_options(c, &grid_options, version);
+			AxesCommonData::write_color_options(c, &grid_options, false, None);
 			c.write_str(", ");
 			AxesCommonData::write_line_options(c, &self.minor_grid_options, version);
 			AxesCommonData::write_color_options(c, &self.minor_grid_options, false, None);
@@ -1176,6 +2513,25 @@ impl AxesCommonData
 		}
 	}
 
+	pub fn write_point_size_options(c: &mut dyn Writer, options: &[PlotOption<String>])
+	{
+		if options
+			.iter()
+			.any(|o| matches!(o, VariablePointSize(_) | VariablePointArea(_)))
+		{
+			c.write_str(" ps variable");
+		}
+		else
+		{
+			first_opt! {options,
+				PointSize(z) =>
+				{
+					write!(c, " ps {}", z);
+				}
+			}
+		}
+	}
+
 	pub fn write_line_options(
 		c: &mut dyn Writer, options: &[PlotOption<String>], version: GnuplotVersion,
 	)
@@ -1197,16 +2553,16 @@ impl AxesCommonData
 		if version.major >= 5
 		{
 			first_opt! {options,
-				LineStyle(d) =>
+				LineStyle(ref d) =>
 				{
-					write!(c, " dt {}", d.to_int());
+					write!(c, " dt {}", d.command());
 				}
 			}
 		}
 		else
 		{
 			first_opt! {options,
-				LineStyle(d) =>
+				LineStyle(ref d) =>
 				{
 					write!(c, " lt {}", d.to_int());
 				}
@@ -1236,9 +2592,14 @@ impl AxesCommonData
 
 	pub fn write_out_commands(
 		&self, writer: &mut dyn Writer, auto_layout: bool, version: GnuplotVersion,
+		shared: SharedLayout,
 	)
 	{
 		let w = writer;
+		if let Some(theme) = self.theme.as_ref()
+		{
+			theme.write_out_commands(w);
+		}
 		if let Some(pos) = self.position
 		{
 			writeln!(w, "set origin {:.12e},{:.12e}", pos.x, pos.y);
@@ -1267,7 +2628,7 @@ impl AxesCommonData
 				writeln!(w, "set size noratio");
 			}
 		}
-		self.margins.write_out_commands(w);
+		self.margins.or(&shared.margins).write_out_commands(w);
 
 		if !self.colormaps.is_empty()
 		{
@@ -1278,6 +2639,10 @@ impl AxesCommonData
 			}
 		}
 		self.palette.write_out_commands(w);
+		if let Some(max_colors) = self.palette_max_colors
+		{
+			writeln!(w, "set palette maxcolors {}", max_colors);
+		}
 
 		if let Some((width, is_relative)) = self.box_width
 		{
@@ -1285,15 +2650,19 @@ impl AxesCommonData
 			writeln!(w, "set boxwidth {width} {scale}");
 		}
 
-		self.x_axis.write_out_commands(w, version);
-		self.y_axis.write_out_commands(w, version);
-		self.x2_axis.write_out_commands(w, version);
-		self.y2_axis.write_out_commands(w, version);
-		self.cb_axis.write_out_commands(w, version);
+		self.x_axis.write_out_commands(w, version, shared.x);
+		self.y_axis.write_out_commands(w, version, shared.y);
+		self.x2_axis.write_out_commands(w, version, None);
+		self.y2_axis.write_out_commands(w, version, None);
+		self.cb_axis.write_out_commands(w, version, None);
+		if let Some(color_box) = self.color_box.as_ref()
+		{
+			color_box.write_out(w);
+		}
 		self.title.write_out_commands(w);
-		for label in &self.labels
+		for (tag, label) in in_z_order(&self.labels, LabelData::z_order)
 		{
-			label.write_out_commands(w);
+			label.write_out_commands_with_tag(tag, w);
 		}
 	}
 
@@ -1302,22 +2671,93 @@ impl AxesCommonData
 		version: GnuplotVersion,
 	)
 	{
-		if let Some(data_directory) = data_directory
+		let sources: Vec<_> = self
+			.elems
+			.iter()
+			.enumerate()
+			.map(|(i, e)| write_data_file(data_directory, &format!("{i}.bin"), |w| e.write_data(w)))
+			.collect();
+
+		for (i, e) in self.elems.iter().enumerate()
+		{
+			e.write_contour_table(&contour_table_name(i), &sources[i], writer);
+		}
+
+		let mut cycle_index = 0;
+		let defaults: Vec<_> = self
+			.elems
+			.iter()
+			.map(|e| match self.style_cycle
+			{
+				Some(ref cycle)
+					if !e.is_empty()
+						&& !matches!(e.source_type, Contours(..))
+						&& (e.plot_type.is_line()
+							|| e.plot_type.is_points()
+							|| matches!(e.plot_type, FillBetween | LineSegments)) =>
+				{
+					cycle_index += 1;
+					cycle.options(cycle_index - 1)
+				}
+				_ => vec![],
+			})
+			.collect();
+
+		// Elements are drawn in the order of their z-order, ties are broken by the insertion order.
+		let mut order: Vec<_> = (0..self.elems.len()).collect();
+		order.sort_by_key(|&i| z_order(&self.elems[i].options));
+
+		// The segments of lines with variable styles are drawn using arrow styles, which are
+		// defined up front.
+		let mut arrow_style_offsets = vec![0; self.elems.len()];
+		let mut num_arrow_styles = 0;
+		let mut plot_idx = 0;
+		for &i in &order
 		{
-			for (i, e) in self.elems.iter().enumerate()
+			let (e, defaults) = (&self.elems[i], &defaults[i]);
+			arrow_style_offsets[i] = num_arrow_styles;
+			if e.is_empty()
+			{
+				continue;
+			}
+			plot_idx += 1;
+			let options = e.combined_options(defaults);
+			let mut color = None;
+			first_opt! {options,
+				Color(ref c) =>
+				{
+					color = Some(c);
+				}
+			}
+			for (width, dash) in &e.segment_styles
 			{
-				let filename = path::Path::new(data_directory).join(format!("{i}.bin"));
-				let mut file = fs::File::create(&filename).unwrap();
-				e.write_data(&mut file);
+				num_arrow_styles += 1;
+				write!(writer, "set style arrow {} nohead", num_arrow_styles);
+				if color.is_none()
+				{
+					// Match the color gnuplot would have picked for a regular line.
+					write!(writer, " lt {}", plot_idx);
+				}
+				AxesCommonData::write_line_options(
+					writer,
+					&[LineWidth(*width), LineStyle(dash.clone())],
+					version,
+				);
+				if let Some(c) = color.filter(|c| !c.is_variable())
+				{
+					write!(writer, " linecolor {}", c.command());
+				}
+				writeln!(writer);
 			}
 		}
 
 		write!(writer, "{}", cmd);
 
 		let mut first = true;
-		for (i, e) in self.elems.iter().enumerate()
+		for &i in &order
 		{
-			if e.num_rows == 0
+			let e = &self.elems[i];
+			if e.is_empty()
 			{
 				continue;
 			}
@@ -1325,40 +2765,128 @@ impl AxesCommonData
 			{
 				write!(writer, ",");
 			}
-			let source = if let Some(data_directory) = data_directory
+			let source = match e.source_type
 			{
-				escape(
-					path::Path::new(data_directory)
-						.join(format!("{i}.bin"))
-						.to_str()
-						.unwrap(),
-				)
-			}
-			else
-			{
-				"-".into()
+				Contours(..) => contour_table_name(i),
+				_ => sources[i].clone(),
 			};
-			e.write_args(&source, writer, version);
+			e.write_args(
+				&source,
+				writer,
+				version,
+				&defaults[i],
+				arrow_style_offsets[i],
+			);
 			first = false;
 		}
 
 		writeln!(writer);
 
-		if data_directory.is_none()
+		// The matrices of contours were already sent with their tables.
+		for &i in order
+			.iter()
+			.filter(|&&i| sources[i] == "-" && !matches!(self.elems[i].source_type, Contours(..)))
 		{
-			for e in self.elems.iter()
+			let e = &self.elems[i];
+			e.write_data(writer);
+			// The outline of filled points reads the data again.
+			let options = e.combined_options(&defaults[i]);
+			if e.point_edge(&options).is_some()
 			{
-				e.write_data(writer);
+				e.write_data_columns(writer, e.point_edge_num_cols(&options));
 			}
 		}
 	}
 
-	pub fn reset_state(&self, writer: &mut dyn Writer)
+	/// Returns the range the X (or Y) axis shows on its own: its fixed ends, with the ends left on
+	/// `Auto` taken from the extents of the elements plotted against it. Used to compute the union of
+	/// the ranges of shared axes.
+	pub fn axis_extent(&self, is_x: bool) -> Option<(f64, f64)>
+	{
+		let (axis, other, other2) = if is_x
+		{
+			(&self.x_axis, &self.y_axis, &self.y2_axis)
+		}
+		else
+		{
+			(&self.y_axis, &self.x_axis, &self.x2_axis)
+		};
+		let data = self
+			.elems
+			.iter()
+			.filter_map(|e| {
+				let (x, y) = e
+					.options
+					.iter()
+					.find_map(|o| match o
+					{
+						Axes(x, y) => Some((*x, *y)),
+						_ => None,
+					})
+					.unwrap_or((XAxis::X1, YAxis::Y1));
+				let (on_axis, on_other) = if is_x
+				{
+					(x == XAxis::X1, y == YAxis::Y1)
+				}
+				else
+				{
+					(y == YAxis::Y1, x == XAxis::X1)
+				};
+				if !on_axis
+				{
+					return None;
+				}
+				let other = if on_other { other } else { other2 };
+				e.extent(is_x, axis.log_base.is_some(), (other.min, other.max))
+			})
+			.reduce(|(lo1, hi1), (lo2, hi2)| (lo1.min(lo2), hi1.max(hi2)));
+		let (lo, hi) = match (axis.min, axis.max, data)
+		{
+			(Fix(min), Fix(max), _) => (min, max),
+			(Fix(min), Auto, Some((_, hi))) => (min, hi),
+			(Auto, Fix(max), Some((lo, _))) => (lo, max),
+			(Auto, Auto, Some(range)) => range,
+			_ => return None,
+		};
+		Some((lo.min(hi), lo.max(hi)))
+	}
+
+	pub fn reset_state(&self, writer: &mut dyn Writer, shared: SharedLayout)
 	{
 		for label in &self.labels
 		{
 			label.reset_state(writer);
 		}
+		for (axis, shared) in [
+			(&self.x_axis, shared.x),
+			(&self.x2_axis, None),
+			(&self.y_axis, shared.y),
+			(&self.y2_axis, None),
+			(&self.cb_axis, None),
+		]
+		{
+			axis.reset_state(writer, shared);
+		}
+		if let Some(color_box) = self.color_box.as_ref()
+		{
+			color_box.reset_state(writer);
+		}
+		if self.palette_max_colors.is_some()
+		{
+			writer.write_str("set palette maxcolors 0\n");
+		}
+		if self.elems.iter().any(|e| !e.segment_styles.is_empty())
+		{
+			writer.write_str("unset style arrow\n");
+		}
+		if let Some(theme) = self.theme.as_ref()
+		{
+			theme.reset_state(writer);
+			if theme.show_grid()
+			{
+				writer.write_str("unset grid\n");
+			}
+		}
 	}
 }
 
@@ -1395,6 +2923,32 @@ pub trait AxesCommon: AxesCommonPrivate
 		self
 	}
 
+	/// Places the axes on the figure according to a grid layout. The plot area of the axes will
+	/// cover the spanned cells, and the axes will be sized and positioned accordingly.
+	/// # Arguments
+	/// * `grid_spec` - The grid layout
+	/// * `rows` - Rows spanned by the axes, counting from the top, starting at 0
+	/// * `columns` - Columns spanned by the axes, counting from the left, starting at 0
+	fn set_grid_spec_cell(
+		&mut self, grid_spec: &GridSpec, rows: Range<usize>, columns: Range<usize>,
+	) -> &mut Self
+	{
+		let (left, bottom, right, top) = grid_spec.cell(rows, columns);
+		let c = self.get_common_data_mut();
+		c.position = Some(Position { x: left, y: bottom });
+		c.size = Some(Size {
+			w: right - left,
+			h: top - bottom,
+		});
+		c.margins = Margins {
+			left: Some(left as f32),
+			right: Some(right as f32),
+			top: Some(top as f32),
+			bottom: Some(bottom as f32),
+		};
+		self
+	}
+
 	/// Set the position of the axes on the figure using screen coordinates.
 	/// The coordinates refer to the bottom-left corner of the axes
 	/// # Arguments
@@ -1526,6 +3080,8 @@ pub trait AxesCommon: AxesCommonPrivate
 	///      * `MarkerSymbol` - Specifies the symbol for the marker. Omit to hide the marker
 	///      * `MarkerSize` - Specifies the size for the marker
 	///      * `MarkerColor` - Specifies the color for the marker
+	///      * `LabelLayer` - Specifies the layer the label is drawn on (default is `Front`)
+	///      * `LabelZOrder` - Specifies the drawing order relative to the other labels
 	fn label<'l>(
 		&'l mut self, text: &str, x: Coordinate, y: Coordinate, options: &[LabelOption<&str>],
 	) -> &'l mut Self
@@ -1970,6 +3526,22 @@ pub trait AxesCommon: AxesCommonPrivate
 		self
 	}
 
+	/// Sets the properties of the color box (the color bar shown next to plots that use the palette).
+	///
+	/// The direction of the color box ticks is set with the `Inward` option of `set_cb_ticks`.
+	///
+	/// # Arguments
+	/// * `show` - Whether to show the color box at all
+	/// * `options` - Array of ColorBoxOption options
+	fn set_cb_box(&mut self, show: bool, options: &[ColorBoxOption]) -> &mut Self
+	{
+		self.get_common_data_mut().color_box = Some(ColorBoxData {
+			show,
+			options: options.to_vec(),
+		});
+		self
+	}
+
 	/// Set the grid options.
 	///
 	/// # Arguments
@@ -1981,11 +3553,23 @@ pub trait AxesCommon: AxesCommonPrivate
 	fn set_grid_options<'l>(&'l mut self, front: bool, options: &[PlotOption<&str>])
 		-> &'l mut Self
 	{
-		self.get_common_data_mut().grid_front = front;
+		self.get_common_data_mut().grid_layer = if front { Front } else { Back };
 		self.get_common_data_mut().grid_options = options.to_one_way_owned();
 		self
 	}
 
+	/// Set the layer the grid is drawn on, relative to the plot elements and the shapes. This is a
+	/// more general version of the `front` argument of `set_grid_options`.
+	///
+	/// # Arguments
+	/// * `layer` - The layer of the grid (default is `Back`). `Behind` is treated as `Back`, as
+	///   gnuplot has no such layer for the grid.
+	fn set_grid_layer(&mut self, layer: LayerType) -> &mut Self
+	{
+		self.get_common_data_mut().grid_layer = layer;
+		self
+	}
+
 	/// Set the minor grid options.
 	///
 	/// # Arguments
@@ -2064,6 +3648,103 @@ pub trait AxesCommon: AxesCommonPrivate
 		self
 	}
 
+	/// Makes the X axis be time and places ticks at calendar-aware locations.
+	///
+	/// If the range is fixed with `set_x_range` (in seconds from the Unix epoch), the ticks are
+	/// computed for it when the figure is written (see `time_ticks`). Otherwise gnuplot places the
+	/// ticks itself after autoscaling: it keeps the spacing of `SecondTicks` through
+	/// `WeekStartTicks`, but picks its own spacing for the other placements.
+	///
+	/// # Arguments
+	/// * `ticks` - Where to place the ticks. `AutoTimeTicks` picks the spacing based on the range.
+	/// * `format` - Format of the tick labels (see `format_time` for valid values). If `None`, a
+	///   format appropriate for the tick spacing is picked.
+	/// * `tick_options` - Array of TickOption controlling the appearance of the ticks
+	/// * `label_options` - Array of LabelOption<&str> controlling the appearance of the tick labels. Relevant options are:
+	///      * `Offset` - Specifies the offset of the label
+	///      * `Font` - Specifies the font of the label
+	///      * `TextColor` - Specifies the color of the label
+	///      * `Rotate` - Specifies the rotation of the label
+	///      * `Align` - Specifies how to align the label
+	fn set_x_time_ticks<'l>(
+		&'l mut self, ticks: TimeTicks, format: Option<&str>, tick_options: &[TickOption<&str>],
+		label_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.get_common_data_mut().set_time_ticks(
+			TickAxis::X,
+			ticks,
+			format,
+			tick_options,
+			label_options,
+		);
+		self
+	}
+
+	/// Like `set_x_time_ticks` but for the Y axis.
+	fn set_y_time_ticks<'l>(
+		&'l mut self, ticks: TimeTicks, format: Option<&str>, tick_options: &[TickOption<&str>],
+		label_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.get_common_data_mut().set_time_ticks(
+			TickAxis::Y,
+			ticks,
+			format,
+			tick_options,
+			label_options,
+		);
+		self
+	}
+
+	/// Like `set_x_time_ticks` but for the secondary X axis.
+	fn set_x2_time_ticks<'l>(
+		&'l mut self, ticks: TimeTicks, format: Option<&str>, tick_options: &[TickOption<&str>],
+		label_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.get_common_data_mut().set_time_ticks(
+			TickAxis::X2,
+			ticks,
+			format,
+			tick_options,
+			label_options,
+		);
+		self
+	}
+
+	/// Like `set_x_time_ticks` but for the secondary Y axis.
+	fn set_y2_time_ticks<'l>(
+		&'l mut self, ticks: TimeTicks, format: Option<&str>, tick_options: &[TickOption<&str>],
+		label_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.get_common_data_mut().set_time_ticks(
+			TickAxis::Y2,
+			ticks,
+			format,
+			tick_options,
+			label_options,
+		);
+		self
+	}
+
+	/// Like `set_x_time_ticks` but for the color bar axis.
+	fn set_cb_time_ticks<'l>(
+		&'l mut self, ticks: TimeTicks, format: Option<&str>, tick_options: &[TickOption<&str>],
+		label_options: &[LabelOption<&str>],
+	) -> &'l mut Self
+	{
+		self.get_common_data_mut().set_time_ticks(
+			TickAxis::CB,
+			ticks,
+			format,
+			tick_options,
+			label_options,
+		);
+		self
+	}
+
 	/// Sets the margins of the plot.
 	///
 	/// # Arguments
@@ -2100,6 +3781,28 @@ pub trait AxesCommon: AxesCommonPrivate
 		self
 	}
 
+	/// Sets the maximum number of discrete colors used to draw the palette, which turns smooth
+	/// palettes into stepped ones. Note that some terminals have their own, lower, limit.
+	///
+	/// # Arguments
+	/// * `max_colors` - The number of colors, or `None` to use as many as the terminal supports
+	fn set_palette_max_colors(&mut self, max_colors: Option<u32>) -> &mut Self
+	{
+		self.get_common_data_mut().palette_max_colors = max_colors;
+		self
+	}
+
+	/// Sets the styles given to successive plot elements that don't specify them explicitly. Lines,
+	/// points, error bars and filled curves take part in the cycle, in the order they were added.
+	///
+	/// # Arguments
+	/// * `cycle` - The style cycle, e.g. `StyleCycle::okabe_ito()`
+	fn set_style_cycle(&mut self, cycle: &StyleCycle) -> &mut Self
+	{
+		self.get_common_data_mut().style_cycle = Some(cycle.clone());
+		self
+	}
+
 	/// Creates and saves a colormap in the gnuplot environment that can be used for
 	/// later plots (see examples/color_variable.rs for example usage)
 	///
@@ -2116,3 +3819,181 @@ pub trait AxesCommon: AxesCommonPrivate
 		self
 	}
 }
+
+#[test]
+fn line_segments_test()
+{
+	use crate::figure::Figure;
+
+	let data = vec![0., 0., 1., 1., 2., 0., 3., 1.];
+	let options = [VariableLineWidth(vec![1., 3., 1., 1.])];
+	let e = PlotElement::new_line_segments(data, 4, 2, &options);
+	// Each segment is a vector from its first vertex, followed by its style index.
+	assert_eq!(e.num_rows, 3);
+	assert_eq!(e.num_cols, 5);
+	assert_eq!(
+		e.data,
+		vec![0., 0., 1., 1., 0., 1., 1., 1., -1., 0., 2., 0., 1., 1., 1.]
+	);
+	assert_eq!(e.segment_styles, vec![(2., Solid), (1., Solid)]);
+
+	let mut fg = Figure::new();
+	fg.axes2d().lines(
+		[0., 1., 2.],
+		[0., 1., 0.],
+		&[VariableLineStyle(vec![Dash, Dot, Dot])],
+	);
+	fg.axes2d().lines([0., 1.], [0., 1.], &[]);
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	assert!(script.contains("set style arrow 1 nohead lt 1 lw 1.000000000000e0 dt 2\n"));
+	assert!(script.contains("set style arrow 2 nohead lt 1 lw 1.000000000000e0 dt 3\n"));
+	// The legend sample uses the style of the first segment.
+	assert!(script.contains("vectors nohead arrowstyle variable lw 1.000000000000e0 dt 2 "));
+	assert!(script.contains("unset style arrow\n"));
+}
+
+#[test]
+fn point_edge_test()
+{
+	let options = [
+		PointSymbol('O'),
+		Color(ColorType::VariableIndex(vec![1, 2])),
+		BorderColor("black".into()),
+	];
+	// Two points with a color index column.
+	let data = vec![0., 0., 1., 1., 1., 2.];
+	let e = PlotElement::new_plot(Points, data, 2, 3, &options);
+	let options = e.combined_options(&[]);
+	assert_eq!(e.point_edge(&options), Some((6, "black".into())));
+	assert_eq!(e.point_edge_num_cols(&options), 2);
+
+	let version = GnuplotVersion { major: 5, minor: 0 };
+	let mut args = vec![];
+	e.write_args("0.bin", &mut args, version, &[], 0);
+	let args = String::from_utf8(args).unwrap();
+	// The outline skips the color column of the data file.
+	assert!(args.contains(
+		r#", "0.bin" binary endian=little record=2 format="%float64%float64%*float64" using 1:2 with points pt 6"#
+	));
+
+	let mut args = vec![];
+	e.write_args("-", &mut args, version, &[], 0);
+	let args = String::from_utf8(args).unwrap();
+	assert!(args.contains(
+		r#", "-" binary endian=little record=2 format="%float64" using 1:2 with points pt 6"#
+	));
+	// Inline data is sent again without the color column.
+	let mut data = vec![];
+	e.write_data_columns(&mut data, 2);
+	assert_eq!(data.len(), 2 * 2 * 8);
+}
+
+#[test]
+#[should_panic(expected = "The outline of points cannot have a variable BorderColor")]
+fn point_edge_variable_test()
+{
+	let options = [
+		PointSymbol('O'),
+		BorderColor(ColorType::VariableIndex(vec![1, 2])),
+	];
+	let e = PlotElement::new_plot(Points, vec![0., 0., 1., 1.], 2, 2, &options);
+	e.point_edge(&e.combined_options(&[]));
+}
+
+#[test]
+fn object_fill_test()
+{
+	let version = GnuplotVersion { major: 5, minor: 0 };
+	let write = |options: &[PlotOption<&str>]| {
+		let object = ObjectData {
+			shape: ObjectShape::Circle(Axis(0.), Axis(0.), Axis(1.)),
+			plot_options: options.to_one_way_owned(),
+		};
+		let mut out = vec![];
+		object.write_out(1, &mut out, version);
+		String::from_utf8(out).unwrap()
+	};
+	// Shapes without a fill color are drawn as an outline, rather than filled with the background.
+	assert!(write(&[]).ends_with(" fillstyle empty border lw 1\n"));
+	assert!(write(&[BorderColor("red".into())])
+		.ends_with(" fillstyle empty border lc rgb \"red\" lw 1\n"));
+	assert!(write(&[Color("red".into())])
+		.ends_with(" fillstyle transparent solid 1.000000000000e0 noborder\n"));
+}
+
+#[test]
+fn time_ticks_range_test()
+{
+	use crate::figure::Figure;
+
+	let day = 24.0 * 3600.0;
+	let mut fg = Figure::new();
+	// The range can be set after the ticks.
+	fg.axes2d()
+		.set_x_time_ticks(DayTicks(1), Some("%d"), &[], &[])
+		.set_x_range(Fix(0.0), Fix(2.0 * day));
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	assert!(script.contains(&format!(
+		r#"set xtics ("01" {:.12e} 0,"02" {:.12e} 0,"03" {:.12e} 0)"#,
+		0.0,
+		day,
+		2.0 * day
+	)));
+}
+
+#[test]
+fn time_ticks_auto_range_test()
+{
+	use crate::figure::Figure;
+
+	let mut fg = Figure::new();
+	fg.axes2d()
+		.set_x_time_ticks(AutoTimeTicks, None, &[], &[])
+		.set_y_time_ticks(HourTicks(2), None, &[], &[])
+		.set_y2_time_ticks(WeekStartTicks, Some("%d"), &[Format("%a")], &[]);
+	// The format is reset for the next axes.
+	fg.axes2d();
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	let script = script.replace(" scale 5.000000000000e-1,5.000000000000e-1", "");
+	assert!(script.contains("set xtics autofreq\n"));
+	// The format follows the spacing, but an explicit one takes precedence over `Format`.
+	assert!(script.contains(&format!(
+		"set ytics {:.12e} format \"%H:%M\\n%b %d\"\n",
+		2.0 * 3600.0
+	)));
+	assert!(script.contains(&format!(
+		"set y2tics {:.12e}, {:.12e} format \"%d\"\n",
+		4.0 * 24.0 * 3600.0,
+		7.0 * 24.0 * 3600.0
+	)));
+	assert!(script.contains("set format y2\n"));
+}
+
+#[test]
+fn grid_spec_test()
+{
+	let mut gs = GridSpec::new(2, 3);
+	gs.set_margins(0.0, 1.0, 0.0, 1.0)
+		.set_spacing(0.1, 0.2)
+		.set_width_ratios(&[1.0, 2.0, 1.0]);
+	let close = |a: (f64, f64, f64, f64), b: (f64, f64, f64, f64)| {
+		assert!(
+			(a.0 - b.0).abs() < 1e-9
+				&& (a.1 - b.1).abs() < 1e-9
+				&& (a.2 - b.2).abs() < 1e-9
+				&& (a.3 - b.3).abs() < 1e-9,
+			"{:?} != {:?}",
+			a,
+			b
+		);
+	};
+	close(gs.cell(0..1, 0..1), (0.0, 0.6, 0.2, 1.0));
+	close(gs.cell(1..2, 1..3), (0.3, 0.0, 1.0, 0.4));
+	close(gs.cell(0..2, 0..3), (0.0, 0.0, 1.0, 1.0));
+}
@@ -1,6 +1,8 @@
 pub use self::ColorType::*;
+use crate::error_types::ColorError;
 use crate::util::OneWayOwned;
 use std::fmt::{Debug, Display};
+use std::str::FromStr;
 
 pub type ColorIndex = u8;
 pub type ColorComponent = u8;
@@ -42,6 +44,8 @@ pub enum ColorType<T = String>
 	/// "#AARRGGBB" represents an RGB color with an alpha channel value in the high bits.
 	/// An alpha value of 255 (FF) represents a fully opaque color; i.e., "#FFRRGGBB" is the same as "#RRGGBB".
 	/// An alpha value of 0 represents full transparency.
+	///
+	/// The string is passed to gnuplot as is, use [ColorType::parse] to validate it up front.
 	RGBString(T),
 	/// tuple of u8 representing red, green and blue values as 0-255
 	RGBInteger(ColorComponent, ColorComponent, ColorComponent),
@@ -178,6 +182,37 @@ impl<T: Display + Debug> ColorType<T>
 		)
 	}
 
+	/// Returns the red, green, blue and alpha components of a fixed color (`RGBString`,
+	/// `RGBInteger`, `ARGBInteger` and `Black`). As elsewhere in this crate, an alpha of 255 is fully
+	/// opaque.
+	///
+	/// Returns `None` if the color is variable, taken from the palette or the color cycle, or is an
+	/// `RGBString` that gnuplot wouldn't accept.
+	pub fn to_rgba(
+		&self,
+	) -> Option<(
+		ColorComponent,
+		ColorComponent,
+		ColorComponent,
+		ColorComponent,
+	)>
+	{
+		match self
+		{
+			RGBString(s) => parse_rgb_string(&s.to_string()).map(|(a, r, g, b)| (r, g, b, a)),
+			RGBInteger(r, g, b) => Some((*r, *g, *b, 255)),
+			ARGBInteger(a, r, g, b) => Some((*r, *g, *b, *a)),
+			Black => Some((0, 0, 0, 255)),
+			_ => None,
+		}
+	}
+
+	/// Returns the red, green and blue components of a fixed color, see `to_rgba`.
+	pub(crate) fn rgb(&self) -> Option<RGBInts>
+	{
+		self.to_rgba().map(|(r, g, b, _)| (r, g, b))
+	}
+
 	pub fn has_alpha(&self) -> bool
 	{
 		match self
@@ -193,6 +228,150 @@ impl<T: Display + Debug> ColorType<T>
 	}
 }
 
+/// Color names known to gnuplot, see `show colornames`.
+const COLOR_NAMES: &[(&str, ColorInt)] = &[
+	("white", 0xffffff),
+	("black", 0x000000),
+	("dark-grey", 0xa0a0a0),
+	("red", 0xff0000),
+	("web-green", 0x00c000),
+	("web-blue", 0x0080ff),
+	("dark-magenta", 0xc000ff),
+	("dark-cyan", 0x00eeee),
+	("dark-orange", 0xc04000),
+	("dark-yellow", 0xc8c800),
+	("royalblue", 0x4169e1),
+	("goldenrod", 0xffc020),
+	("dark-spring-green", 0x008040),
+	("purple", 0xc080ff),
+	("steelblue", 0x306080),
+	("dark-red", 0x8b0000),
+	("dark-chartreuse", 0x408000),
+	("orchid", 0xff80ff),
+	("aquamarine", 0x7fffd4),
+	("brown", 0xa52a2a),
+	("yellow", 0xffff00),
+	("turquoise", 0x40e0d0),
+	("grey0", 0x000000),
+	("grey10", 0x1a1a1a),
+	("grey20", 0x333333),
+	("grey30", 0x4d4d4d),
+	("grey40", 0x666666),
+	("grey50", 0x7f7f7f),
+	("grey60", 0x999999),
+	("grey70", 0xb3b3b3),
+	("grey", 0xc0c0c0),
+	("grey80", 0xcccccc),
+	("grey90", 0xe5e5e5),
+	("grey100", 0xffffff),
+	("light-red", 0xf03232),
+	("light-green", 0x90ee90),
+	("light-blue", 0xadd8e6),
+	("light-magenta", 0xf055f0),
+	("light-cyan", 0xe0ffff),
+	("light-goldenrod", 0xeedd82),
+	("light-pink", 0xffb6c1),
+	("light-turquoise", 0xafeeee),
+	("gold", 0xffd700),
+	("green", 0x00ff00),
+	("dark-green", 0x006400),
+	("spring-green", 0x00ff7f),
+	("forest-green", 0x228b22),
+	("sea-green", 0x2e8b57),
+	("blue", 0x0000ff),
+	("dark-blue", 0x00008b),
+	("midnight-blue", 0x191970),
+	("navy", 0x000080),
+	("medium-blue", 0x0000cd),
+	("skyblue", 0x87ceeb),
+	("cyan", 0x00ffff),
+	("magenta", 0xff00ff),
+	("dark-turquoise", 0x00ced1),
+	("dark-pink", 0xff1493),
+	("coral", 0xff7f50),
+	("light-coral", 0xf08080),
+	("orange-red", 0xff4500),
+	("salmon", 0xfa8072),
+	("dark-salmon", 0xe9967a),
+	("khaki", 0xf0e68c),
+	("dark-khaki", 0xbdb76b),
+	("dark-goldenrod", 0xb8860b),
+	("beige", 0xf5f5dc),
+	("olive", 0xa08020),
+	("orange", 0xffa500),
+	("violet", 0xee82ee),
+	("dark-violet", 0x9400d3),
+	("plum", 0xdda0dd),
+	("dark-plum", 0x905040),
+	("dark-olivegreen", 0x556b2f),
+	("orangered4", 0x801400),
+	("brown4", 0x801414),
+	("sienna4", 0x804014),
+	("orchid4", 0x804080),
+	("mediumpurple3", 0x8060c0),
+	("slateblue1", 0x8060ff),
+	("yellow4", 0x808000),
+	("sienna1", 0xff8040),
+	("tan1", 0xffa040),
+	("sandybrown", 0xffa060),
+	("light-salmon", 0xffa070),
+	("pink", 0xffc0c0),
+	("khaki1", 0xffff80),
+	("lemonchiffon", 0xffffc0),
+	("bisque", 0xcdb79e),
+	("honeydew", 0xf0fff0),
+	("slategrey", 0xa0b6cd),
+	("seagreen", 0xc1ffc1),
+	("antiquewhite", 0xcdc0b0),
+	("chartreuse", 0x7cff40),
+	("greenyellow", 0xa0ff20),
+	("gray", 0xbebebe),
+	("light-gray", 0xd3d3d3),
+	("light-grey", 0xd3d3d3),
+	("dark-gray", 0xa0a0a0),
+	("slategray", 0xa0b6cd),
+	("gray0", 0x000000),
+	("gray10", 0x1a1a1a),
+	("gray20", 0x333333),
+	("gray30", 0x4d4d4d),
+	("gray40", 0x666666),
+	("gray50", 0x7f7f7f),
+	("gray60", 0x999999),
+	("gray70", 0xb3b3b3),
+	("gray80", 0xcccccc),
+	("gray90", 0xe5e5e5),
+	("gray100", 0xffffff),
+];
+
+/// Parses a color name or a hex color in one of the formats accepted by `RGBString`, returning its
+/// alpha, red, green and blue components.
+fn parse_rgb_string(s: &str) -> Option<ARGBInts>
+{
+	let argb = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("#"))
+	{
+		if !hex.chars().all(|c| c.is_ascii_hexdigit())
+		{
+			return None;
+		}
+		match hex.len()
+		{
+			6 => 0xff000000 | ColorInt::from_str_radix(hex, 16).ok()?,
+			8 => ColorInt::from_str_radix(hex, 16).ok()?,
+			_ => return None,
+		}
+	}
+	else
+	{
+		0xff000000 | COLOR_NAMES.iter().find(|&&(name, _)| name == s)?.1
+	};
+	Some((
+		(argb >> 24) as u8,
+		(argb >> 16) as u8,
+		(argb >> 8) as u8,
+		argb as u8,
+	))
+}
+
 fn from_argb(a: ColorComponent, r: ColorComponent, g: ColorComponent, b: ColorComponent)
 	-> ColorInt
 {
@@ -288,33 +467,72 @@ fn floats_to_argb(a: f64, r: f64, g: f64, b: f64) -> Result<ARGBInts, String>
 	))
 }
 
+/// Checks a color converted with `into()` in debug builds, see [ColorType::parse]
+fn debug_check_rgb_string(s: &str)
+{
+	debug_assert!(
+		parse_rgb_string(s).is_some(),
+		"Invalid color {:?}, see `ColorType::parse` for the accepted formats",
+		s
+	);
+}
+
 impl<'l> From<&'l str> for ColorType<String>
 {
-	/// Converts `&str` into [RGBString]
+	/// Converts `&str` into [RGBString]. The color is only checked in debug builds, where an
+	/// invalid color panics. This is the unchecked way to create a color, use [ColorType::parse] to
+	/// handle invalid colors instead.
 	fn from(value: &'l str) -> Self
 	{
+		debug_check_rgb_string(value);
 		ColorType::RGBString(String::from(value))
 	}
 }
 
 impl<'l> From<String> for ColorType<String>
 {
-	/// Converts `String` into [RGBString]
+	/// Converts `String` into [RGBString]. The color is only checked in debug builds, where an
+	/// invalid color panics. This is the unchecked way to create a color, use [ColorType::parse] to
+	/// handle invalid colors instead.
 	fn from(value: String) -> Self
 	{
+		debug_check_rgb_string(&value);
 		ColorType::RGBString(value)
 	}
 }
 
 impl<'l> From<&'l str> for ColorType<&'l str>
 {
-	/// Converts `&str` into [RGBString]
+	/// Converts `&str` into [RGBString]. The color is only checked in debug builds, where an
+	/// invalid color panics. This is the unchecked way to create a color, use [ColorType::parse] to
+	/// handle invalid colors instead.
 	fn from(value: &'l str) -> Self
 	{
+		debug_check_rgb_string(value);
 		ColorType::RGBString(value)
 	}
 }
 
+impl<T> From<ColorInt> for ColorType<T>
+{
+	/// Converts a hex integer `0xRRGGBB` into [RGBInteger]. The highest byte is ignored.
+	fn from(value: ColorInt) -> Self
+	{
+		ColorType::RGBInteger((value >> 16) as u8, (value >> 8) as u8, value as u8)
+	}
+}
+
+impl<T> From<[f32; 3]> for ColorType<T>
+{
+	/// Converts `[f32; 3]` red, green and blue values in the range `0 <= v <= 1` into [RGBInteger].
+	/// Values outside of that range are clamped to it.
+	fn from(value: [f32; 3]) -> Self
+	{
+		let [r, g, b] = value.map(|v| (v.clamp(0., 1.) * 255.).round() as u8);
+		ColorType::RGBInteger(r, g, b)
+	}
+}
+
 impl<T> From<ARGBInts> for ColorType<T>
 {
 	/// Converts `(u8, u8, u8, u8)` into [ARGBInteger]
@@ -420,6 +638,24 @@ impl<T: Display> OneWayOwned for ColorType<T>
 
 impl ColorType<String>
 {
+	/// Parses a color in one of the formats accepted by [RGBString], i.e. a gnuplot color name or
+	/// a hex color. Unlike converting a string with `into()`, this catches typos and malformed
+	/// colors right away rather than when gnuplot renders the plot.
+	///
+	/// ```
+	/// use gnuplot::ColorType;
+	/// assert_eq!(ColorType::parse("#0000ff").unwrap().to_rgba(), Some((0, 0, 255, 255)));
+	/// assert!(ColorType::parse("blu").is_err());
+	/// ```
+	pub fn parse(s: &str) -> Result<Self, ColorError>
+	{
+		match parse_rgb_string(s)
+		{
+			Some(_) => Ok(RGBString(s.into())),
+			None => Err(ColorError::new(s)),
+		}
+	}
+
 	pub fn to_ref(&self) -> ColorType<&str>
 	{
 		match self
@@ -440,3 +676,36 @@ impl ColorType<String>
 		}
 	}
 }
+
+impl FromStr for ColorType<String>
+{
+	type Err = ColorError;
+
+	/// See [ColorType::parse]
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		Self::parse(s)
+	}
+}
+
+#[test]
+fn color_parse_test()
+{
+	let parse = |s| ColorType::parse(s).ok().and_then(|c| c.to_rgba());
+	assert_eq!(parse("blue"), Some((0, 0, 255, 255)));
+	assert_eq!(parse("#0000FF"), Some((0, 0, 255, 255)));
+	assert_eq!(parse("0x800000ff"), Some((0, 0, 255, 128)));
+	assert_eq!(parse("blu"), None);
+	assert_eq!(parse("#00f"), None);
+	assert_eq!(parse("0x+0000ff"), None);
+
+	assert_eq!(
+		ColorType::<String>::from(0x8000ffu32),
+		RGBInteger(128, 0, 255)
+	);
+	assert_eq!(
+		ColorType::<String>::from([0.5f32, 0., 2.]),
+		RGBInteger(128, 0, 255)
+	);
+	assert_eq!(PaletteFracColor::<String>(0.5).to_rgba(), None);
+}
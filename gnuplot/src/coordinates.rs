@@ -31,3 +31,39 @@ impl fmt::Display for Coordinate
 		write!(buf, "{}{:.16e}", name, x)
 	}
 }
+
+/// Specifies a point in 3D, with each component interpreted according to its `Coordinate`.
+/// Note that `Axis2` components are only meaningful for the X and Y axes.
+#[derive(Copy, Clone)]
+pub struct Coordinate3D
+{
+	pub x: Coordinate,
+	pub y: Coordinate,
+	pub z: Coordinate,
+}
+
+impl Coordinate3D
+{
+	/// Creates a new point from its components
+	pub fn new(x: Coordinate, y: Coordinate, z: Coordinate) -> Self
+	{
+		Coordinate3D { x, y, z }
+	}
+}
+
+impl From<(Coordinate, Coordinate, Coordinate)> for Coordinate3D
+{
+	/// Converts `(x, y, z)` into a `Coordinate3D`
+	fn from((x, y, z): (Coordinate, Coordinate, Coordinate)) -> Self
+	{
+		Coordinate3D { x, y, z }
+	}
+}
+
+impl fmt::Display for Coordinate3D
+{
+	fn fmt(&self, buf: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(buf, "{},{},{}", self.x, self.y, self.z)
+	}
+}
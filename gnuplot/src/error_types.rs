@@ -44,3 +44,123 @@ impl error::Error for GnuplotInitError
 		Some(&*self.inner)
 	}
 }
+
+/// An error encountered while parsing a theme, see `Theme::from_toml`
+#[cfg(feature = "toml")]
+pub struct ThemeError
+{
+	line: Option<usize>,
+	message: String,
+}
+
+#[cfg(feature = "toml")]
+impl ThemeError
+{
+	pub(crate) fn new(line: Option<usize>, message: String) -> Self
+	{
+		ThemeError { line, message }
+	}
+
+	/// The 1-based line the error occurred on, if the document is not valid TOML. Errors in the
+	/// values of the keys have no line, their message names the key instead.
+	pub fn line(&self) -> Option<usize>
+	{
+		self.line
+	}
+}
+
+#[cfg(feature = "toml")]
+impl fmt::Display for ThemeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		match self.line
+		{
+			Some(line) => write!(f, "Invalid theme on line {}: {}", line, self.message),
+			None => write!(f, "Invalid theme: {}", self.message),
+		}
+	}
+}
+
+#[cfg(feature = "toml")]
+impl fmt::Debug for ThemeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self)
+	}
+}
+
+#[cfg(feature = "toml")]
+impl error::Error for ThemeError {}
+
+/// An error returned when creating an invalid dash type, see `DashType::custom` and
+/// `DashType::pattern`
+pub struct DashTypeError
+{
+	message: String,
+}
+
+impl DashTypeError
+{
+	pub(crate) fn new(message: String) -> Self
+	{
+		DashTypeError { message }
+	}
+}
+
+impl fmt::Display for DashTypeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(f, "Invalid dash type: {}", self.message)
+	}
+}
+
+impl fmt::Debug for DashTypeError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self)
+	}
+}
+
+impl error::Error for DashTypeError {}
+
+/// An error returned when parsing an invalid color, see `ColorType::parse`
+pub struct ColorError
+{
+	color: String,
+}
+
+impl ColorError
+{
+	pub(crate) fn new(color: &str) -> Self
+	{
+		ColorError {
+			color: color.into(),
+		}
+	}
+}
+
+impl fmt::Display for ColorError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(
+			f,
+			"Invalid color \"{}\". Expected a gnuplot color name, #RRGGBB, #AARRGGBB, 0xRRGGBB or 0xAARRGGBB.",
+			self.color
+		)
+	}
+}
+
+impl fmt::Debug for ColorError
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self)
+	}
+}
+
+impl error::Error for ColorError {}
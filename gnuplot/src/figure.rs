@@ -7,8 +7,12 @@ use crate::error_types::*;
 use self::AxesVariant::*;
 use crate::axes2d::*;
 use crate::axes3d::*;
-
-use crate::options::{GnuplotVersion, MultiplotFillDirection, MultiplotFillOrder};
+use crate::axes_common::{
+	AxesCommon, AxesCommonPrivate, Margins, SharedAxis, SharedLayout, PANEL_AREA,
+};
+use crate::coordinates::*;
+use crate::options::*;
+use crate::style::Theme;
 use crate::util::escape;
 use crate::writer::Writer;
 use std::fs::File;
@@ -20,8 +24,8 @@ use tempfile;
 
 enum AxesVariant
 {
-	Axes2DType(Axes2D),
-	Axes3DType(Axes3D),
+	Axes2DType(Box<Axes2D>),
+	Axes3DType(Box<Axes3D>),
 	NewPage,
 }
 
@@ -29,12 +33,12 @@ impl AxesVariant
 {
 	fn write_out(
 		&self, data_directory: Option<&str>, writer: &mut dyn Writer, auto_layout: bool,
-		version: GnuplotVersion,
+		version: GnuplotVersion, shared: SharedLayout,
 	)
 	{
 		match *self
 		{
-			Axes2DType(ref a) => a.write_out(data_directory, writer, auto_layout, version),
+			Axes2DType(ref a) => a.write_out(data_directory, writer, auto_layout, version, shared),
 			Axes3DType(ref a) => a.write_out(data_directory, writer, auto_layout, version),
 			NewPage =>
 			{
@@ -44,11 +48,11 @@ impl AxesVariant
 		}
 	}
 
-	fn reset_state(&self, writer: &mut dyn Writer)
+	fn reset_state(&self, writer: &mut dyn Writer, shared: SharedLayout)
 	{
 		match *self
 		{
-			Axes2DType(ref a) => a.reset_state(writer),
+			Axes2DType(ref a) => a.reset_state(writer, shared),
 			Axes3DType(ref a) => a.reset_state(writer),
 			_ => (),
 		}
@@ -87,6 +91,45 @@ impl MultiplotOptions
 	}
 }
 
+/// Screen space between panels with shared axes, with and without the inner tick labels hidden
+const SHARED_AXES_SPACING: (f32, f32) = (0.02, 0.1);
+/// Screen space between the panels of a set of broken axes
+const BROKEN_AXES_GAP: f32 = 0.03;
+/// Screen size of the break marks of a set of broken axes
+const BROKEN_AXES_MARK_SIZE: f32 = 0.01;
+
+/// A set of 2D axes panels that together show a single plot with discontinuities along one of the axes.
+/// Created by `Figure::broken_axes`.
+pub struct BrokenAxes<'l>
+{
+	panels: Vec<&'l mut Axes2D>,
+}
+
+impl<'l> BrokenAxes<'l>
+{
+	/// Calls `f` for every panel. Use this to issue plot commands, so that the data shows up in all of
+	/// the panels. Captions are only kept in the first panel, so that the legend is shown once.
+	pub fn plot<F: FnMut(&mut Axes2D)>(&mut self, mut f: F) -> &mut Self
+	{
+		for (i, panel) in self.panels.iter_mut().enumerate()
+		{
+			f(panel);
+			if i > 0
+			{
+				panel.hide_captions();
+			}
+		}
+		self
+	}
+
+	/// Returns the individual panels, ordered from the start of the broken axis (bottom or left)
+	/// to its end. Use this to customize the panels individually.
+	pub fn panels(&mut self) -> &mut [&'l mut Axes2D]
+	{
+		&mut self.panels
+	}
+}
+
 /// A sentinel that represents a gnuplot waiting to close.
 pub struct CloseSentinel
 {
@@ -137,6 +180,9 @@ pub struct Figure
 	multiplot_options: Option<MultiplotOptions>,
 	data_directory: Option<String>,
 	data_tempdir: Option<tempfile::TempDir>,
+	shared_x: Vec<Vec<usize>>,
+	shared_y: Vec<Vec<usize>>,
+	theme: Option<Theme>,
 }
 
 impl Default for GnuplotVersion
@@ -176,6 +222,9 @@ impl Figure
 				.and_then(|d| d.path().to_str())
 				.map(|s| s.into()),
 			data_tempdir: data_tempdir,
+			shared_x: vec![],
+			shared_y: vec![],
+			theme: None,
 		}
 	}
 
@@ -346,10 +395,32 @@ impl Figure
 		self
 	}
 
+	/// Sets the theme of the figure, which provides the default font, background, text color, border,
+	/// grid and legend style of all of its axes, including those already created.
+	///
+	/// The background is drawn together with the first axes of each page, so it covers the multiplot
+	/// title, if any.
+	pub fn set_theme(&mut self, theme: &Theme) -> &mut Self
+	{
+		self.theme = Some(theme.clone());
+		for axes in &mut self.axes
+		{
+			match *axes
+			{
+				Axes2DType(ref mut a) => a.set_theme(Some(theme)),
+				Axes3DType(ref mut a) => a.set_theme(Some(theme)),
+				NewPage => (),
+			}
+		}
+		self
+	}
+
 	/// Creates a set of 2D axes
 	pub fn axes2d(&mut self) -> &mut Axes2D
 	{
-		self.axes.push(Axes2DType(Axes2D::new()));
+		let mut axes = Axes2D::new();
+		axes.set_theme(self.theme.as_ref());
+		self.axes.push(Axes2DType(Box::new(axes)));
 		let l = self.axes.len();
 		match self.axes[l - 1]
 		{
@@ -361,7 +432,9 @@ impl Figure
 	/// Creates a set of 3D axes
 	pub fn axes3d(&mut self) -> &mut Axes3D
 	{
-		self.axes.push(Axes3DType(Axes3D::new()));
+		let mut axes = Axes3D::new();
+		axes.set_theme(self.theme.as_ref());
+		self.axes.push(Axes3DType(Box::new(axes)));
 		let l = self.axes.len();
 		match self.axes[l - 1]
 		{
@@ -370,6 +443,369 @@ impl Figure
 		}
 	}
 
+	/// Creates a set of 2D axes with discontinuities along one axis. Each range is shown in its own
+	/// panel, with the panel size proportional to the length of the range. The panels share the other
+	/// axis (as with `share_x_axes` or `share_y_axes`), so it has the same range and ticks in all of
+	/// them. They have their inner borders hidden and break marks drawn at the discontinuities.
+	///
+	/// The panels are laid out using explicit margins, so this is best used on its own figure, without
+	/// a multiplot layout (which is also implied by `set_title`).
+	///
+	/// # Arguments
+	/// * `axis` - Which axis is broken
+	/// * `ranges` - The `(min, max)` ranges of the broken axis to show, ordered from the bottom (or left)
+	pub fn broken_axes(&mut self, axis: BrokenAxis, ranges: &[(f64, f64)]) -> BrokenAxes<'_>
+	{
+		let (left, right, bottom, top) = PANEL_AREA;
+		let n = ranges.len();
+		let total_range: f64 = ranges.iter().map(|&(lo, hi)| (hi - lo).abs()).sum();
+		let available = match axis
+		{
+			BrokenX => right - left,
+			BrokenY => top - bottom,
+		} - BROKEN_AXES_GAP * n.saturating_sub(1) as f32;
+
+		let first = self.axes.len();
+		let first_index = self.axes.iter().filter(|a| !matches!(a, NewPage)).count();
+		let group = (first_index..first_index + n).collect();
+		match axis
+		{
+			BrokenX => self.shared_y.push(group),
+			BrokenY => self.shared_x.push(group),
+		}
+		let mut start = match axis
+		{
+			BrokenX => left,
+			BrokenY => bottom,
+		};
+		for (i, &(lo, hi)) in ranges.iter().enumerate()
+		{
+			let len = if total_range > 0.0
+			{
+				available * ((hi - lo).abs() / total_range) as f32
+			}
+			else
+			{
+				available / n as f32
+			};
+			let (is_first, is_last) = (i == 0, i + 1 == n);
+			let (width, height) = match axis
+			{
+				BrokenX => (len, top - bottom),
+				BrokenY => (right - left, len),
+			};
+			let dx = (BROKEN_AXES_MARK_SIZE / width) as f64;
+			let dy = (BROKEN_AXES_MARK_SIZE / height) as f64;
+			let mark_options = [ArrowType(NoArrow)];
+
+			let a = self.axes2d();
+			match axis
+			{
+				BrokenX =>
+				{
+					a.set_margins(&[
+						MarginLeft(start),
+						MarginRight(start + len),
+						MarginBottom(bottom),
+						MarginTop(top),
+					])
+					.set_x_range(Fix(lo), Fix(hi));
+					let mut borders = vec![Bottom, Top];
+					if is_first
+					{
+						borders.push(Left);
+						a.set_y_ticks(Some((Auto, 0)), &[Mirror(false)], &[]);
+					}
+					else
+					{
+						a.set_y_ticks(None, &[], &[]);
+						for y in [0.0, 1.0]
+						{
+							a.arrow(
+								Graph(-dx),
+								Graph(y - dy),
+								Graph(dx),
+								Graph(y + dy),
+								&mark_options,
+							);
+						}
+					}
+					if is_last
+					{
+						borders.push(Right);
+					}
+					else
+					{
+						for y in [0.0, 1.0]
+						{
+							a.arrow(
+								Graph(1.0 - dx),
+								Graph(y - dy),
+								Graph(1.0 + dx),
+								Graph(y + dy),
+								&mark_options,
+							);
+						}
+					}
+					a.set_border(true, &borders, &[]);
+				}
+				BrokenY =>
+				{
+					a.set_margins(&[
+						MarginLeft(left),
+						MarginRight(right),
+						MarginBottom(start),
+						MarginTop(start + len),
+					])
+					.set_y_range(Fix(lo), Fix(hi));
+					let mut borders = vec![Left, Right];
+					if is_first
+					{
+						borders.push(Bottom);
+						a.set_x_ticks(Some((Auto, 0)), &[Mirror(false)], &[]);
+					}
+					else
+					{
+						a.set_x_ticks(None, &[], &[]);
+						for x in [0.0, 1.0]
+						{
+							a.arrow(
+								Graph(x - dx),
+								Graph(-dy),
+								Graph(x + dx),
+								Graph(dy),
+								&mark_options,
+							);
+						}
+					}
+					if is_last
+					{
+						borders.push(Top);
+					}
+					else
+					{
+						for x in [0.0, 1.0]
+						{
+							a.arrow(
+								Graph(x - dx),
+								Graph(1.0 - dy),
+								Graph(x + dx),
+								Graph(1.0 + dy),
+								&mark_options,
+							);
+						}
+					}
+					a.set_border(true, &borders, &[]);
+				}
+			}
+			start += len + BROKEN_AXES_GAP;
+		}
+
+		BrokenAxes {
+			panels: self.axes[first..]
+				.iter_mut()
+				.map(|a| match *a
+				{
+					Axes2DType(ref mut a) => &mut **a,
+					_ => unreachable!(),
+				})
+				.collect(),
+		}
+	}
+
+	/// Makes the given 2D axes share their X axis. The axes are referred to by their index, counting the
+	/// `axes2d` and `axes3d` calls, starting at 0. The sharing is resolved when the figure is shown or
+	/// saved, so the axes don't need to exist yet, but the figure panics then if an index doesn't refer
+	/// to a 2D axes.
+	///
+	/// Shared axes have their auto-ranges unified, so they all show the union of the ranges they'd show
+	/// on their own. The union is computed from the plotted data, so it doesn't account for e.g. the
+	/// widths of boxes, and the auto-ranged ends are not extended to the next tick. If a multiplot
+	/// layout is set (see `set_multiplot_layout`), the
+	/// shared axes also get aligned margins (unless set with `set_margins`) and the X tick labels are
+	/// only shown on the bottom-most axes of each column.
+	pub fn share_x_axes(&mut self, axes: &[usize]) -> &mut Self
+	{
+		self.shared_x.push(axes.to_vec());
+		self
+	}
+
+	/// Like `share_x_axes`, but for the Y axis. The Y tick labels are only shown on the left-most
+	/// axes of each row.
+	pub fn share_y_axes(&mut self, axes: &[usize]) -> &mut Self
+	{
+		self.shared_y.push(axes.to_vec());
+		self
+	}
+
+	/// Maps an axes index (which doesn't count new pages) to an index into `self.axes`
+	fn axes_variant_index(&self, index: usize) -> Option<usize>
+	{
+		self.axes
+			.iter()
+			.enumerate()
+			.filter(|(_, a)| !matches!(a, NewPage))
+			.nth(index)
+			.map(|(i, _)| i)
+	}
+
+	/// Returns the `(row, column)` of the multiplot layout cell the axes is placed in
+	fn layout_cell(&self, index: usize) -> Option<(usize, usize)>
+	{
+		let m = self.multiplot_options.as_ref()?;
+		let (rows, columns) = (m.rows.max(1), m.columns.max(1));
+		let index = index % (rows * columns);
+		let (row, column) = match m.fill_order
+		{
+			Some(MultiplotFillOrder::ColumnsFirst) => (index % rows, index / rows),
+			_ => (index / columns, index % columns),
+		};
+		let row = match m.fill_direction
+		{
+			Some(MultiplotFillDirection::Upwards) => rows - 1 - row,
+			_ => row,
+		};
+		Some((row, column))
+	}
+
+	/// Resolves the groups of shared axes into the layout of each axes, indexed like `self.axes`
+	fn shared_layouts(&self) -> Vec<SharedLayout>
+	{
+		let mut layouts = vec![SharedLayout::default(); self.axes.len()];
+		if self.shared_x.is_empty() && self.shared_y.is_empty()
+		{
+			return layouts;
+		}
+
+		let (left, right, bottom, top) = PANEL_AREA;
+		let (rows, columns) = self
+			.multiplot_options
+			.as_ref()
+			.map(|m| (m.rows.max(1), m.columns.max(1)))
+			.unwrap_or((1, 1));
+		let (narrow, wide) = SHARED_AXES_SPACING;
+		let spacing_x = if self.shared_y.is_empty()
+		{
+			wide
+		}
+		else
+		{
+			narrow
+		};
+		let spacing_y = if self.shared_x.is_empty()
+		{
+			wide
+		}
+		else
+		{
+			narrow
+		};
+		let width = (right - left - spacing_x * (columns - 1) as f32) / columns as f32;
+		let height = (top - bottom - spacing_y * (rows - 1) as f32) / rows as f32;
+
+		for (is_x, groups) in [(true, &self.shared_x), (false, &self.shared_y)]
+		{
+			for group in groups
+			{
+				let indices: Vec<_> = group
+					.iter()
+					.map(|&index| {
+						let i = self.axes_variant_index(index).unwrap_or_else(|| {
+							panic!(
+								"Cannot share the axes {index}: the figure only has {} axes",
+								self.axes.iter().filter(|a| !matches!(a, NewPage)).count()
+							)
+						});
+						assert!(
+							matches!(self.axes[i], Axes2DType(_)),
+							"Cannot share the axes {index}: only 2D axes can be shared"
+						);
+						i
+					})
+					.collect();
+				let range = indices
+					.iter()
+					.filter_map(|&i| match self.axes[i]
+					{
+						Axes2DType(ref a) => a.get_common_data().axis_extent(is_x),
+						_ => None,
+					})
+					.reduce(|(lo1, hi1), (lo2, hi2)| (lo1.min(lo2), hi1.max(hi2)));
+				let cells: Vec<_> = group.iter().map(|&i| self.layout_cell(i)).collect();
+				for (&i, &cell) in indices.iter().zip(&cells)
+				{
+					// Tick labels are shown on the outermost axes of the group in each column (or row).
+					let hide_tick_labels = cell
+						.map(|(row, column)| {
+							cells.iter().flatten().any(|&(other_row, other_column)| {
+								if is_x
+								{
+									other_column == column && other_row > row
+								}
+								else
+								{
+									other_row == row && other_column < column
+								}
+							})
+						})
+						.unwrap_or(false);
+					let layout = &mut layouts[i];
+					let shared = Some(SharedAxis {
+						range,
+						hide_tick_labels,
+					});
+					if is_x
+					{
+						layout.x = shared;
+					}
+					else
+					{
+						layout.y = shared;
+					}
+					if let Some((row, column)) = cell
+					{
+						let x = left + column as f32 * (width + spacing_x);
+						let y = top - row as f32 * (height + spacing_y);
+						layout.margins = Margins {
+							left: Some(x),
+							right: Some(x + width),
+							top: Some(y),
+							bottom: Some(y - height),
+						};
+					}
+				}
+			}
+		}
+		layouts
+	}
+
+	/// Whether the axes need to be drawn in a multiplot
+	fn is_multiplot(&self) -> bool
+	{
+		self.axes.len() > 1
+			|| self.multiplot_options.is_some()
+			|| self.axes.iter().any(|a| match a
+			{
+				Axes2DType(ref a) => a.has_insets(),
+				_ => false,
+			})
+	}
+
+	fn axes_data_directory(&self, i: usize) -> Option<String>
+	{
+		let out_path = self.data_directory.as_ref().and_then(|d| {
+			Path::new(&d)
+				.join(i.to_string())
+				.to_str()
+				.map(|s| s.to_string())
+		});
+		if let Some(out_path) = out_path.as_ref()
+		{
+			std::fs::create_dir_all(out_path).ok();
+		}
+		out_path
+	}
+
 	/// Creates a new page.
 	///
 	/// Some terminals support multiple pages or frames, e.g. to create an
@@ -611,6 +1047,7 @@ impl Figure
 		}
 
 		writeln!(w, "set encoding utf8");
+		let shared_layouts = self.shared_layouts();
 		if !self.terminal.is_empty()
 		{
 			writeln!(w, "set terminal {}", self.terminal);
@@ -638,8 +1075,12 @@ impl Figure
 				"noenhanced"
 			}
 		);
+		if let Some(theme) = self.theme.as_ref()
+		{
+			theme.write_out_terminal_options(w);
+		}
 
-		if self.axes.len() > 1 || self.multiplot_options.is_some()
+		if self.is_multiplot()
 		{
 			let mut multiplot_options_string = "".to_string();
 			if let Some(m) = &self.multiplot_options
@@ -684,33 +1125,35 @@ impl Figure
 			writeln!(w, "set multiplot{}", multiplot_options_string);
 		}
 
-		let mut prev_e: Option<&AxesVariant> = None;
-		for (i, e) in self.axes.iter().enumerate()
+		let mut prev_e: Option<(&AxesVariant, SharedLayout)> = None;
+		for (i, (e, &shared)) in self.axes.iter().zip(&shared_layouts).enumerate()
 		{
-			if let Some(prev_e) = prev_e
+			if let Some((prev_e, prev_shared)) = prev_e
 			{
-				prev_e.reset_state(w);
+				prev_e.reset_state(w, prev_shared);
 			}
-			let out_path = self.data_directory.as_ref().and_then(|d| {
-				Path::new(&d)
-					.join(i.to_string())
-					.to_str()
-					.map(|s| s.to_string())
-			});
-			if let Some(out_path) = out_path.as_ref()
+			// The background is drawn by the first axes of each page.
+			let page_start = !matches!(e, NewPage) && matches!(prev_e, None | Some((NewPage, _)));
+			if let (true, Some(theme)) = (page_start, self.theme.as_ref())
 			{
-				std::fs::create_dir_all(out_path).ok();
+				theme.write_out_background(w);
 			}
+			let out_path = self.axes_data_directory(i);
 			e.write_out(
 				out_path.as_deref(),
 				w,
 				self.multiplot_options.is_some(),
 				self.get_gnuplot_version(),
+				shared,
 			);
-			prev_e = Some(e);
+			if let (true, Some(theme)) = (page_start, self.theme.as_ref())
+			{
+				theme.reset_background(w);
+			}
+			prev_e = Some((e, shared));
 		}
 
-		if self.axes.len() > 1 || self.multiplot_options.is_some()
+		if self.is_multiplot()
 		{
 			writeln!(w, "unset multiplot");
 		}
@@ -743,6 +1186,99 @@ impl Drop for Figure
 	}
 }
 
+#[test]
+fn shared_axes_test()
+{
+	let mut fg = Figure::new();
+	// The sharing is resolved when writing, so it doesn't matter that the axes and the layout are
+	// created afterwards.
+	fg.share_x_axes(&[0, 1]);
+	fg.axes2d()
+		.set_margins(&[MarginLeft(0.2)])
+		.lines([0., 2.], [0., 1.], &[])
+		.x_error_bars([1.], [0.], [2.], &[]);
+	fg.axes2d()
+		.set_x_range(Auto, Fix(2.))
+		.lines([-1., 1.], [0., 1.], &[])
+		// Not plotted against the shared axis.
+		.points([-10.], [0.], &[Axes(XAxis::X2, YAxis::Y1)]);
+	fg.set_multiplot_layout(2, 1);
+
+	let layouts = fg.shared_layouts();
+	assert_eq!(
+		layouts[0].x,
+		Some(SharedAxis {
+			range: Some((-1., 3.)),
+			hide_tick_labels: true
+		})
+	);
+	assert_eq!(
+		layouts[1].x,
+		Some(SharedAxis {
+			range: Some((-1., 3.)),
+			hide_tick_labels: false
+		})
+	);
+	assert_eq!(layouts[0].y, None);
+	assert_eq!(layouts[0].margins.top, Some(PANEL_AREA.3));
+	assert!((layouts[1].margins.bottom.unwrap() - PANEL_AREA.2).abs() < 1e-6);
+
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	// The margins set by the user take precedence.
+	assert!(script.contains("set lmargin at screen 0.2\n"));
+	assert!(script.contains(&format!("set lmargin at screen {}\n", PANEL_AREA.0)));
+	// Only the ends left on Auto take the shared range.
+	assert!(script.contains("set xrange [-1.000000000000e0:3.000000000000e0]\n"));
+	assert!(script.contains("set xrange [-1.000000000000e0:2.000000000000e0]\n"));
+	assert!(!script.contains("GPVAL"));
+}
+
+#[test]
+#[should_panic(expected = "Cannot share the axes 2: the figure only has 2 axes")]
+fn shared_axes_missing_test()
+{
+	let mut fg = Figure::new();
+	fg.axes2d();
+	fg.axes2d();
+	fg.share_y_axes(&[0, 2]);
+	fg.echo(&mut vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot share the axes 1: only 2D axes can be shared")]
+fn shared_axes_3d_test()
+{
+	let mut fg = Figure::new();
+	fg.axes2d();
+	fg.axes3d();
+	fg.share_x_axes(&[0, 1]);
+	fg.echo(&mut vec![]);
+}
+
+#[test]
+fn broken_axes_test()
+{
+	let mut fg = Figure::new();
+	fg.broken_axes(BrokenX, &[(0., 3.), (7., 10.)]).plot(|a| {
+		// The point in the gap doesn't contribute to the shared Y range.
+		a.lines([0., 2., 5., 8., 10.], [1., 2., 50., 4., 3.], &[]);
+	});
+
+	let mut script = vec![];
+	fg.echo(&mut script);
+	let script = String::from_utf8(script).unwrap();
+	assert_eq!(
+		script
+			.matches("set yrange [1.000000000000e0:4.000000000000e0]\n")
+			.count(),
+		2
+	);
+	assert!(script.contains("set xrange [0.000000000000e0:3.000000000000e0]\n"));
+	assert!(script.contains("set xrange [7.000000000000e0:1.000000000000e1]\n"));
+}
+
 #[test]
 fn flush_test()
 {
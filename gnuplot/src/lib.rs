@@ -25,13 +25,15 @@ fg.show();
 */
 pub use crate::axes2d::Axes2D;
 pub use crate::axes3d::Axes3D;
-pub use crate::axes_common::AxesCommon;
+pub use crate::axes_common::{AxesCommon, GridSpec};
 pub use crate::color::*;
 pub use crate::coordinates::*;
 pub use crate::datatype::*;
 pub use crate::error_types::*;
 pub use crate::figure::*;
 pub use crate::options::*;
+pub use crate::style::*;
+pub use crate::time_ticks::*;
 
 #[macro_use]
 mod util;
@@ -46,4 +48,6 @@ mod error_types;
 mod figure;
 mod options;
 pub mod palettes;
+mod style;
+mod time_ticks;
 mod writer;
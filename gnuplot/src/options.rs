@@ -5,23 +5,36 @@
 pub use self::AlignType::*;
 pub use self::ArrowheadType::*;
 pub use self::AutoOption::*;
+pub use self::BarLayout::*;
 pub use self::BorderLocation2D::*;
+pub use self::BorderLocation3D::*;
+pub use self::BrokenAxis::*;
+pub use self::ColorBoxOption::*;
 pub use self::ContourStyle::*;
 pub use self::DashType::*;
+pub use self::EqualAxes3D::*;
 pub use self::FillPatternType::*;
 pub use self::FillRegionType::*;
 pub use self::LabelOption::*;
+pub use self::LayerType::*;
 pub use self::LegendOption::*;
 pub use self::MarginSide::*;
+pub use self::PaletteInterpolation::*;
 pub use self::PaletteType::*;
 pub use self::PlotOption::*;
+pub use self::ProjectionPlane::*;
 pub use self::Tick::*;
 pub use self::TickOption::*;
+pub use self::VoxelStyle::*;
 pub use self::XAxis::*;
+pub use self::XYPlane::*;
 pub use self::YAxis::*;
-use crate::util::OneWayOwned;
+use crate::error_types::DashTypeError;
+use crate::util::{escape, OneWayOwned};
 use crate::writer::Writer;
 use crate::ColorType;
+use crate::RGBInts;
+use std::f64::consts::PI;
 
 /// An enumeration of plot options you can supply to plotting commands, governing
 /// things like line width, color and others
@@ -44,9 +57,24 @@ pub enum PlotOption<T>
 	/// * `D` - filled del (upside down triangle)
 	/// * `r` - empty rhombus
 	/// * `R` - filled rhombus
+	///
+	/// The filled symbols can be given an outline of a different color with `BorderColor`.
 	PointSymbol(char),
+	/// Uses an arbitrary character (e.g. `'★'`) as the symbol for points. Unlike `PointSymbol`,
+	/// this is drawn as text, so it looks the same on all terminals as long as the font has the
+	/// glyph. Requires gnuplot 5.
+	PointGlyph(char),
 	/// Sets the size of the points. The size acts as a multiplier, with 1.0 being the default.
 	PointSize(f64),
+	/// Sets the symbol of each point, see `PointSymbol` for the valid characters. This adds an extra
+	/// data column, and is only relevant for elements drawn with points.
+	VariablePointSymbol(Vec<char>),
+	/// Sets the size of each point, as a multiplier like `PointSize`. This adds an extra data column,
+	/// and is only relevant for elements drawn with points.
+	VariablePointSize(Vec<f64>),
+	/// Like `VariablePointSize`, but the values set the area of each point rather than its size, so
+	/// that e.g. a value twice as large gives a point that looks twice as large. Useful for bubble charts.
+	VariablePointArea(Vec<f64>),
 	/// Sets the caption of the plot element. Set to empty to hide it from the legend.
 	Caption(T),
 	/// Sets the width of lines.
@@ -54,11 +82,20 @@ pub enum PlotOption<T>
 	/// Sets the color of the plot element. The passed string can be a color name
 	/// (e.g. "black" works), or an HTML color specifier (e.g. "#FFFFFF" is white). This specifies the fill color of a filled plot.
 	Color(ColorType<T>),
-	/// Sets the color of the border of a filled plot (if it has one). The passed string can be a color name
-	/// (e.g. "black" works), or an HTML color specifier (e.g. "#FFFFFF" is white).
+	/// Sets the color of the border of a filled plot (if it has one), or of the outline of filled points. The
+	/// passed string can be a color name (e.g. "black" works), or an HTML color specifier (e.g. "#FFFFFF" is white).
+	///
+	/// The outline of points is drawn as a second set of points on top of the first, so it cannot
+	/// have a variable color, and inline data (see `Figure::set_data_directory`) is sent twice.
 	BorderColor(ColorType<T>),
 	/// Sets the style of the line. Note that not all gnuplot terminals support dashed lines. See DashType for the available styles.
 	LineStyle(DashType),
+	/// Sets the width of the line at each point. Each segment of the line takes the average width of
+	/// its ends. Only relevant for 2D lines.
+	VariableLineWidth(Vec<f64>),
+	/// Sets the style of each segment of the line, the `n`th segment going from the `n`th point to the
+	/// next one. See DashType for the available styles. Only relevant for 2D lines.
+	VariableLineStyle(Vec<DashType>),
 	/// Sets the transparency of a filled plot. `0.0` - fully transparent, `1.0` - fully opaque. Cannot be used with `FillPattern`.
 	FillAlpha(f64),
 	/// Sets the fill region. See `FillRegionType` for the available regions.
@@ -76,6 +113,20 @@ pub enum PlotOption<T>
 	Axes(XAxis, YAxis),
 	/// Box width set per box for box plots: each element is the width of one box
 	BoxWidth(Vec<f64>),
+	/// Sets the layer the element is drawn on. See `LayerType` for the available layers. Only used by
+	/// arrows (e.g. `Axes2D::arrow` and `Axes2D::axhline`) and shapes (e.g. `Axes2D::rectangle`
+	/// and `Axes2D::axhspan`), plot elements are always drawn in the plot layer.
+	Layer(LayerType),
+	/// Whether or not to clip the element to the plot area. Only used by shapes (e.g.
+	/// `Axes2D::rectangle` and `Axes2D::axhspan`), plot elements are always clipped.
+	Clip(bool),
+	/// Sets the drawing order of the element relative to others of the same kind (plot elements,
+	/// arrows or shapes): elements with higher values are drawn on top. Elements with equal values
+	/// are drawn in the order they were added. The default is `0`.
+	///
+	/// Note that the legend lists the plot elements in the order they are drawn, so this also
+	/// reorders their legend entries.
+	ZOrder(i32),
 }
 
 impl<'l> OneWayOwned for PlotOption<&'l str>
@@ -86,12 +137,18 @@ impl<'l> OneWayOwned for PlotOption<&'l str>
 		match *self
 		{
 			PointSymbol(v) => PointSymbol(v),
+			PointGlyph(v) => PointGlyph(v),
 			PointSize(v) => PointSize(v),
+			VariablePointSymbol(ref d) => VariablePointSymbol(d.clone()),
+			VariablePointSize(ref d) => VariablePointSize(d.clone()),
+			VariablePointArea(ref d) => VariablePointArea(d.clone()),
 			Caption(v) => Caption(v.into()),
 			LineWidth(v) => LineWidth(v),
 			Color(ref v) => Color(v.to_one_way_owned()),
 			BorderColor(ref v) => BorderColor(v.to_one_way_owned()),
-			LineStyle(v) => LineStyle(v),
+			LineStyle(ref v) => LineStyle(v.clone()),
+			VariableLineWidth(ref d) => VariableLineWidth(d.clone()),
+			VariableLineStyle(ref d) => VariableLineStyle(d.clone()),
 			FillAlpha(v) => FillAlpha(v),
 			FillRegion(v) => FillRegion(v),
 			ArrowType(v) => ArrowType(v),
@@ -100,6 +157,9 @@ impl<'l> OneWayOwned for PlotOption<&'l str>
 			FillPattern(v) => FillPattern(v),
 			Axes(x, y) => Axes(x, y),
 			BoxWidth(ref d) => BoxWidth(d.clone()),
+			Layer(v) => Layer(v),
+			Clip(v) => Clip(v),
+			ZOrder(v) => ZOrder(v),
 		}
 	}
 }
@@ -141,7 +201,10 @@ pub enum AlignType
 }
 
 /// An enumeration of possible dash styles
-#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+///
+/// Note that since version 0.0.47, this is no longer `Copy`, `Eq` or `Ord`, as the custom patterns
+/// hold a `Vec` and floats. Use `clone` where it used to be copied.
+#[derive(Clone, Debug, PartialOrd, PartialEq)]
 pub enum DashType
 {
 	Solid,
@@ -150,10 +213,67 @@ pub enum DashType
 	Dash,
 	DotDash,
 	DotDotDash,
+	/// A custom dash pattern, made of 1 to 5 pairs of dash and gap lengths. The lengths are
+	/// multiples of the line width. Requires gnuplot 5, older versions draw a solid line instead.
+	/// See `DashType::custom` for a checked way to create it.
+	CustomDash(Vec<(f64, f64)>),
+	/// A dash pattern given as a string made of the characters `.` (dot), `-` (short dash), `_` (long
+	/// dash) and ` ` (gap), e.g. `"-. "`. Requires gnuplot 5, older versions draw a solid line
+	/// instead. See `DashType::pattern` for a checked way to create it.
+	DashPattern(String),
 }
 
 impl DashType
 {
+	/// Creates a `CustomDash`, checking that there are 1 to 5 pairs of dash and gap lengths and
+	/// that the lengths are positive.
+	///
+	/// ```
+	/// use gnuplot::DashType;
+	/// assert!(DashType::custom(&[(4., 2.), (1., 2.)]).is_ok());
+	/// assert!(DashType::custom(&[(4., 0.)]).is_err());
+	/// ```
+	pub fn custom(pattern: &[(f64, f64)]) -> Result<Self, DashTypeError>
+	{
+		if !(1..=5).contains(&pattern.len())
+		{
+			return Err(DashTypeError::new(format!(
+				"expected 1 to 5 pairs of dash and gap lengths, got {}",
+				pattern.len()
+			)));
+		}
+		let positive = |l: f64| l > 0. && l.is_finite();
+		if let Some((dash, gap)) = pattern
+			.iter()
+			.find(|&&(dash, gap)| !positive(dash) || !positive(gap))
+		{
+			return Err(DashTypeError::new(format!(
+				"the dash and gap lengths must be positive, got ({dash}, {gap})"
+			)));
+		}
+		Ok(CustomDash(pattern.to_vec()))
+	}
+
+	/// Creates a `DashPattern`, checking that it is made of the characters `.`, `-`, `_` and ` `.
+	///
+	/// ```
+	/// use gnuplot::DashType;
+	/// assert!(DashType::pattern("-. ").is_ok());
+	/// assert!(DashType::pattern("-x").is_err());
+	/// ```
+	pub fn pattern(pattern: &str) -> Result<Self, DashTypeError>
+	{
+		if pattern.is_empty() || !pattern.chars().all(|c| ".-_ ".contains(c))
+		{
+			return Err(DashTypeError::new(format!(
+				"expected a pattern made of `.`, `-`, `_` and ` `, got \"{pattern}\""
+			)));
+		}
+		Ok(DashPattern(pattern.into()))
+	}
+
+	/// Returns the gnuplot dash type number, which is what gnuplot 4 uses. The custom patterns
+	/// have none and return the number of a solid line.
 	pub fn to_int(&self) -> i32
 	{
 		match *self
@@ -164,6 +284,79 @@ impl DashType
 			Dot => 3,
 			DotDash => 4,
 			DotDotDash => 5,
+			CustomDash(_) | DashPattern(_) => 1,
+		}
+	}
+
+	/// Returns the gnuplot string that will produce the requested dash type
+	pub fn command(&self) -> String
+	{
+		match self
+		{
+			CustomDash(pattern) =>
+			{
+				// Gnuplot supports at most 5 pairs.
+				assert!(
+					(1..=5).contains(&pattern.len()),
+					"CustomDash needs 1 to 5 pairs of dash and gap lengths, got {}",
+					pattern.len()
+				);
+				format!(
+					"({})",
+					pattern
+						.iter()
+						.map(|(dash, gap)| format!("{dash},{gap}"))
+						.collect::<Vec<_>>()
+						.join(",")
+				)
+			}
+			DashPattern(pattern) => format!("\"{}\"", escape(pattern)),
+			d => d.to_int().to_string(),
+		}
+	}
+}
+
+/// How to lay out multiple bar series sharing the same categories
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum BarLayout
+{
+	/// Bars of each category are placed side by side
+	GroupedBars,
+	/// Bars of each category are stacked on top of each other. Negative values are stacked below the X axis.
+	StackedBars,
+}
+
+/// An enumeration of the layers an annotation can be drawn on
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum LayerType
+{
+	/// On top of the plot elements
+	Front,
+	/// Below the plot elements, but above the grid and the axes
+	Back,
+	/// Below everything, including the grid and the axes
+	Behind,
+}
+
+impl LayerType
+{
+	pub fn to_str(&self) -> &'static str
+	{
+		match *self
+		{
+			Front => "front",
+			Back => "back",
+			Behind => "behind",
+		}
+	}
+
+	/// For the things that only have the front and back layers.
+	pub(crate) fn front_or_back_str(&self) -> &'static str
+	{
+		match *self
+		{
+			Front => "front",
+			Back | Behind => "back",
 		}
 	}
 }
@@ -256,6 +449,12 @@ pub enum LabelOption<T>
 	MarkerColor(ColorType<T>),
 	/// Sets the size of the marker. The size acts as a multiplier, with 1.0 being the default.
 	MarkerSize(f64),
+	/// Sets the layer the label is drawn on (default is `Front`). Gnuplot has no `Behind` layer for
+	/// labels, so it is treated as `Back`.
+	LabelLayer(LayerType),
+	/// Sets the drawing order of the label relative to other labels on the same layer: labels with
+	/// higher values are drawn on top. The default is `0`.
+	LabelZOrder(i32),
 }
 
 impl<'l> OneWayOwned for LabelOption<&'l str>
@@ -273,6 +472,8 @@ impl<'l> OneWayOwned for LabelOption<&'l str>
 			MarkerSymbol(v) => MarkerSymbol(v),
 			MarkerColor(v) => MarkerColor(v.to_one_way_owned()),
 			MarkerSize(v) => MarkerSize(v),
+			LabelLayer(v) => LabelLayer(v),
+			LabelZOrder(v) => LabelZOrder(v),
 		}
 	}
 }
@@ -345,6 +546,35 @@ pub enum BorderLocation2D
 	Right = 8,
 }
 
+/// Plot border locations for 3D plots. These are the edges of the box surrounding the plot, as
+/// seen from the default view.
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum BorderLocation3D
+{
+	BottomLeftFront = 1,
+	BottomLeftBack = 2,
+	BottomRightFront = 4,
+	BottomRightBack = 8,
+	LeftVertical = 16,
+	BackVertical = 32,
+	RightVertical = 64,
+	FrontVertical = 128,
+	TopLeftBack = 256,
+	TopRightBack = 512,
+	TopLeftFront = 1024,
+	TopRightFront = 2048,
+}
+
+/// Which axis of a set of broken axes has the discontinuities
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum BrokenAxis
+{
+	/// The X axis is broken, the panels are placed side by side
+	BrokenX,
+	/// The Y axis is broken, the panels are stacked on top of each other
+	BrokenY,
+}
+
 /// Plot margins
 #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 pub enum MarginSide
@@ -394,6 +624,27 @@ impl<'l> OneWayOwned for LegendOption<&'l str>
 	}
 }
 
+/// Color box (the color bar) options
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub enum ColorBoxOption
+{
+	/// Makes the color box horizontal (default is vertical)
+	ColorBoxHorizontal,
+	/// Places the color box inside the plot area, along its right edge (or along the bottom edge, if
+	/// the color box is horizontal)
+	ColorBoxInside,
+	/// Places the color box at the given origin `(x, y)` with the given size `(w, h)`, all in screen
+	/// coordinates
+	ColorBoxScreen(f64, f64, f64, f64),
+	/// Places the color box at the given origin `(x, y)` with the given size `(w, h)`, all in graph
+	/// coordinates. Only supported for 2D plots.
+	ColorBoxGraph(f64, f64, f64, f64),
+	/// Whether to draw a border around the color box (default is `true`)
+	ColorBoxBorder(bool),
+	/// Whether to draw the color box in front of the plot elements (default is `true`)
+	ColorBoxFront(bool),
+}
+
 /// Specifies how the contours are drawn
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub enum ContourStyle
@@ -414,6 +665,49 @@ pub enum ContourStyle
 	Spline(u32, u32),
 }
 
+/// Specifies which axes of a 3D plot are scaled equally
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum EqualAxes3D
+{
+	/// The X and Y axes have the same scale
+	EqualXY,
+	/// The X, Y and Z axes all have the same scale
+	EqualXYZ,
+}
+
+/// Specifies the plane a 3D plot is projected onto, looking straight down the remaining axis
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum ProjectionPlane
+{
+	/// Looking down the Z axis, same as the map view
+	ProjectionXY,
+	/// Looking down the Y axis
+	ProjectionXZ,
+	/// Looking down the X axis
+	ProjectionYZ,
+}
+
+/// Specifies where the XY plane of a 3D plot is drawn
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub enum XYPlane
+{
+	/// Places the XY plane below the bottom of the Z range, at a distance given as a fraction of
+	/// the total Z range. `0.0` makes it touch the bottom of the Z range, and the default is `0.5`.
+	XYPlaneRelative(f64),
+	/// Places the XY plane at the specified Z value
+	XYPlaneAt(f64),
+}
+
+/// Specifies how a voxel grid is drawn
+#[derive(Clone, Debug, PartialOrd, PartialEq)]
+pub enum VoxelStyle
+{
+	/// Draws a surface enclosing the voxels with values above each of the levels
+	Isosurfaces(Vec<f64>),
+	/// Draws a point at each voxel with a value above the threshold, colored by the value
+	VoxelPoints(f64),
+}
+
 /// Specifies what sort of palette to use
 #[derive(Clone, Debug, PartialOrd, PartialEq)]
 pub enum PaletteType<T>
@@ -430,6 +724,8 @@ pub enum PaletteType<T>
 	/// element is the grayscale value that is mapped to the remaining three elements
 	/// which specify the red, green and blue components of the color.
 	/// The grayscale values must be non-decreasing. All values must range from 0 to 1.
+	/// Repeating a grayscale value creates a sharp boundary between two colors (see
+	/// [stepped_palette][crate::palettes::stepped_palette]).
 	Custom(T),
 }
 
@@ -461,6 +757,35 @@ pub const HELIX: PaletteType<&'static [(f32, f32, f32, f32)]> = CubeHelix(0.5, -
 
 impl PaletteType<Vec<(f32, f32, f32, f32)>>
 {
+	/// Borrows the palette, e.g. to pass it to `AxesCommon::set_palette`
+	pub fn to_ref(&self) -> PaletteType<&[(f32, f32, f32, f32)]>
+	{
+		match *self
+		{
+			Gray(v) => Gray(v),
+			Formula(v1, v2, v3) => Formula(v1, v2, v3),
+			CubeHelix(v1, v2, v3, v4) => CubeHelix(v1, v2, v3, v4),
+			Custom(ref v) => Custom(v),
+		}
+	}
+
+	/// Returns the palette with its colors in the opposite order. Only `Custom` palettes can be
+	/// reversed, `None` is returned for the other types.
+	pub fn reversed(&self) -> Option<Self>
+	{
+		match *self
+		{
+			Custom(ref entries) => Some(Custom(
+				entries
+					.iter()
+					.rev()
+					.map(|&(x, r, g, b)| (1.0 - x, r, g, b))
+					.collect(),
+			)),
+			_ => None,
+		}
+	}
+
 	pub fn write_out_commands(&self, w: &mut dyn Writer)
 	{
 		match *self
@@ -519,6 +844,284 @@ impl PaletteType<Vec<(f32, f32, f32, f32)>>
 	}
 }
 
+/// Specifies the color space used to interpolate between the entries of a `Custom` palette when
+/// sampling it
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum PaletteInterpolation
+{
+	/// Interpolate the sRGB components linearly, which is what gnuplot does
+	InterpolateRGB,
+	/// Interpolate in the perceptually uniform OKLab color space
+	InterpolateOKLab,
+}
+
+impl<T: AsRef<[(f32, f32, f32, f32)]>> PaletteType<T>
+{
+	/// Returns the color the palette maps the fraction `frac` (between 0 and 1) of the color range to,
+	/// computed the same way gnuplot does it.
+	pub fn sample(&self, frac: f64) -> RGBInts
+	{
+		self.sample_in(frac, InterpolateRGB)
+	}
+
+	/// Like `sample`, but with the color space used to interpolate the entries of a `Custom`
+	/// palette. The other palette types are defined by formulas and are not affected by it.
+	pub fn sample_in(&self, frac: f64, interpolation: PaletteInterpolation) -> RGBInts
+	{
+		let x = frac.clamp(0.0, 1.0);
+		let (r, g, b) = match *self
+		{
+			Gray(gamma) =>
+			{
+				let v = x.powf(1.0 / gamma as f64);
+				(v, v, v)
+			}
+			Formula(r, g, b) => (
+				palette_formula(r, x),
+				palette_formula(g, x),
+				palette_formula(b, x),
+			),
+			CubeHelix(start, cycles, saturation, gamma) =>
+			{
+				let phi = 2.0 * PI * (start as f64 / 3.0 + x * cycles as f64);
+				let v = x.powf(1.0 / gamma as f64);
+				let a = saturation as f64 * v * (1.0 - v) / 2.0;
+				let (cos, sin) = (phi.cos(), phi.sin());
+				(
+					v + a * (-0.14861 * cos + 1.78277 * sin),
+					v + a * (-0.29227 * cos - 0.90649 * sin),
+					v + a * (1.97294 * cos),
+				)
+			}
+			Custom(ref entries) =>
+			{
+				let entries = entries.as_ref();
+				assert!(!entries.is_empty(), "Cannot sample an empty custom palette");
+				let (x0, x1) = (entries[0].0 as f64, entries[entries.len() - 1].0 as f64);
+				// Gnuplot maps the gray levels of the entries to the whole color range.
+				let x = if x1 > x0 { x0 + x * (x1 - x0) } else { x0 };
+				let i = entries
+					.iter()
+					.position(|e| e.0 as f64 > x)
+					.unwrap_or(entries.len())
+					.clamp(1, entries.len().max(2) - 1);
+				let (lo, hi) = (entries[i - 1], entries[i.min(entries.len() - 1)]);
+				let t = if hi.0 > lo.0
+				{
+					((x - lo.0 as f64) / (hi.0 - lo.0) as f64).clamp(0.0, 1.0)
+				}
+				else
+				{
+					1.0
+				};
+				let lo = (lo.1 as f64, lo.2 as f64, lo.3 as f64);
+				let hi = (hi.1 as f64, hi.2 as f64, hi.3 as f64);
+				match interpolation
+				{
+					InterpolateRGB => lerp3(lo, hi, t),
+					InterpolateOKLab => oklab_to_rgb(lerp3(rgb_to_oklab(lo), rgb_to_oklab(hi), t)),
+				}
+			}
+		};
+		let to_int = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+		(to_int(r), to_int(g), to_int(b))
+	}
+
+	/// Returns `n` colors evenly sampled from the palette, from its start to its end. This is useful
+	/// to color plot elements consistently with the palette.
+	pub fn to_colors(&self, n: usize) -> Vec<ColorType>
+	{
+		self.to_colors_in(n, InterpolateRGB)
+	}
+
+	/// Like `to_colors`, but with the color space used to interpolate the entries of a `Custom`
+	/// palette.
+	pub fn to_colors_in(&self, n: usize, interpolation: PaletteInterpolation) -> Vec<ColorType>
+	{
+		(0..n)
+			.map(|i| {
+				let frac = if n > 1
+				{
+					i as f64 / (n - 1) as f64
+				}
+				else
+				{
+					0.5
+				};
+				self.sample_in(frac, interpolation).into()
+			})
+			.collect()
+	}
+}
+
+/// Evaluates one of gnuplot's `rgbformulae`, see `show palette rgbformulae`
+fn palette_formula(formula: i32, x: f64) -> f64
+{
+	let deg = PI / 180.0;
+	let v = match formula.abs()
+	{
+		0 => 0.0,
+		1 => 0.5,
+		2 => 1.0,
+		3 => x,
+		4 => x * x,
+		5 => x * x * x,
+		6 => x * x * x * x,
+		7 => x.sqrt(),
+		8 => x.sqrt().sqrt(),
+		9 => (90.0 * x * deg).sin(),
+		10 => (90.0 * x * deg).cos(),
+		11 => (x - 0.5).abs(),
+		12 => (2.0 * x - 1.0) * (2.0 * x - 1.0),
+		13 => (180.0 * x * deg).sin(),
+		14 => (180.0 * x * deg).cos().abs(),
+		15 => (360.0 * x * deg).sin(),
+		16 => (360.0 * x * deg).cos(),
+		17 => (360.0 * x * deg).sin().abs(),
+		18 => (360.0 * x * deg).cos().abs(),
+		19 => (720.0 * x * deg).sin().abs(),
+		20 => (720.0 * x * deg).cos().abs(),
+		21 => 3.0 * x,
+		22 => 3.0 * x - 1.0,
+		23 => 3.0 * x - 2.0,
+		24 => (3.0 * x - 1.0).abs(),
+		25 => (3.0 * x - 2.0).abs(),
+		26 => (3.0 * x - 1.0) / 2.0,
+		27 => (3.0 * x - 2.0) / 2.0,
+		28 => ((3.0 * x - 1.0) / 2.0).abs(),
+		29 => ((3.0 * x - 2.0) / 2.0).abs(),
+		30 => x / 0.32 - 0.78125,
+		31 => 2.0 * x - 0.84,
+		32 =>
+		{
+			if x < 0.25
+			{
+				4.0 * x
+			}
+			else if x < 0.42
+			{
+				1.0
+			}
+			else if x < 0.92
+			{
+				-2.0 * x + 1.84
+			}
+			else
+			{
+				x / 0.08 - 11.5
+			}
+		}
+		33 => (2.0 * x - 0.5).abs(),
+		34 => 2.0 * x,
+		35 => 2.0 * x - 0.5,
+		36 => 2.0 * x - 1.0,
+		_ => panic!("Invalid formula: {}", formula),
+	};
+	let v = v.clamp(0.0, 1.0);
+	if formula < 0
+	{
+		1.0 - v
+	}
+	else
+	{
+		v
+	}
+}
+
+fn lerp3(a: (f64, f64, f64), b: (f64, f64, f64), t: f64) -> (f64, f64, f64)
+{
+	(
+		a.0 + (b.0 - a.0) * t,
+		a.1 + (b.1 - a.1) * t,
+		a.2 + (b.2 - a.2) * t,
+	)
+}
+
+fn srgb_to_linear(v: f64) -> f64
+{
+	if v <= 0.04045
+	{
+		v / 12.92
+	}
+	else
+	{
+		((v + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+fn linear_to_srgb(v: f64) -> f64
+{
+	if v <= 0.0031308
+	{
+		12.92 * v
+	}
+	else
+	{
+		1.055 * v.powf(1.0 / 2.4) - 0.055
+	}
+}
+
+/// Converts sRGB to OKLab, see https://bottosson.github.io/posts/oklab/
+fn rgb_to_oklab((r, g, b): (f64, f64, f64)) -> (f64, f64, f64)
+{
+	let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+	let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+	let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+	let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+	(
+		0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+		1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+		0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+	)
+}
+
+/// Converts OKLab to sRGB, see https://bottosson.github.io/posts/oklab/
+fn oklab_to_rgb((lightness, a, b): (f64, f64, f64)) -> (f64, f64, f64)
+{
+	let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+	let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+	let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+	(
+		linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+		linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+		linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+	)
+}
+
+#[test]
+fn palette_sample_test()
+{
+	let custom = Custom(vec![(0.0, 0.0, 0.0, 0.0), (1.0, 1.0, 0.5, 0.0)]);
+	assert_eq!(custom.sample(0.0), (0, 0, 0));
+	assert_eq!(custom.sample(0.5), (128, 64, 0));
+	assert_eq!(custom.sample(1.0), (255, 128, 0));
+	// The perceptual midpoint between black and white is darker than the sRGB one.
+	let gray = Custom(vec![(0.0, 0.0, 0.0, 0.0), (1.0, 1.0, 1.0, 1.0)]);
+	assert_eq!(gray.sample_in(1.0, InterpolateOKLab), (255, 255, 255));
+	assert!(gray.sample_in(0.5, InterpolateOKLab).0 < gray.sample(0.5).0);
+
+	let stepped = Custom(vec![
+		(0.0, 1.0, 0.0, 0.0),
+		(0.5, 1.0, 0.0, 0.0),
+		(0.5, 0.0, 0.0, 1.0),
+		(1.0, 0.0, 0.0, 1.0),
+	]);
+	assert_eq!(stepped.sample(0.25), (255, 0, 0));
+	assert_eq!(stepped.sample(0.75), (0, 0, 255));
+
+	let formula: PaletteType<Vec<_>> = Formula(7, 5, 15);
+	assert_eq!(formula.sample(0.0), (0, 0, 0));
+	assert_eq!(formula.sample(1.0), (255, 255, 0));
+	assert_eq!(
+		Gray::<Vec<_>>(1.0).to_colors(3),
+		vec![
+			ColorType::RGBInteger(0, 0, 0),
+			ColorType::RGBInteger(128, 128, 128),
+			ColorType::RGBInteger(255, 255, 255)
+		]
+	);
+}
+
 /// Gnuplot version identifier. This is used to handle version-specific
 /// features.
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
@@ -566,3 +1169,40 @@ pub enum MultiplotFillDirection
 	/// "upward" gnuplot option.
 	Upwards,
 }
+
+#[test]
+fn dash_type_command_test()
+{
+	assert_eq!(Dash.command(), "2");
+	assert_eq!(
+		CustomDash(vec![(10., 5.), (2.5, 5.)]).command(),
+		"(10,5,2.5,5)"
+	);
+	assert_eq!(DashPattern("-. ".into()).command(), "\"-. \"");
+}
+
+#[test]
+#[should_panic(expected = "CustomDash needs 1 to 5 pairs of dash and gap lengths, got 6")]
+fn dash_type_too_long_test()
+{
+	CustomDash(vec![(1., 1.); 6]).command();
+}
+
+#[test]
+fn dash_type_checked_test()
+{
+	assert_eq!(
+		DashType::custom(&[(4., 2.)]).unwrap(),
+		CustomDash(vec![(4., 2.)])
+	);
+	assert!(DashType::custom(&[]).is_err());
+	assert!(DashType::custom(&[(1., 1.); 6]).is_err());
+	assert!(DashType::custom(&[(1., -1.)]).is_err());
+	assert!(DashType::custom(&[(f64::NAN, 1.)]).is_err());
+	assert_eq!(
+		DashType::pattern("-_. ").unwrap(),
+		DashPattern("-_. ".into())
+	);
+	assert!(DashType::pattern("").is_err());
+	assert!(DashType::pattern("-\"").is_err());
+}
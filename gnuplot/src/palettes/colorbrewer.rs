@@ -0,0 +1,553 @@
+// Color values taken from ColorBrewer 2.0 (https://colorbrewer2.org) by Cynthia A. Brewer,
+// Geography, Pennsylvania State University, released under the Apache License 2.0.
+
+use crate::options::PaletteType;
+
+/// The ColorBrewer sequential `Blues` palette
+pub const BLUES: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.984314, 1.0),
+	(1. / 8., 0.870588, 0.921569, 0.968627),
+	(2. / 8., 0.776471, 0.858824, 0.937255),
+	(3. / 8., 0.619608, 0.792157, 0.882353),
+	(4. / 8., 0.419608, 0.682353, 0.839216),
+	(5. / 8., 0.258824, 0.572549, 0.776471),
+	(6. / 8., 0.129412, 0.443137, 0.709804),
+	(7. / 8., 0.031373, 0.317647, 0.611765),
+	(1., 0.031373, 0.188235, 0.419608),
+]);
+
+/// The ColorBrewer sequential `BuGn` palette
+pub const BU_GN: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.988235, 0.992157),
+	(1. / 8., 0.898039, 0.960784, 0.976471),
+	(2. / 8., 0.8, 0.92549, 0.901961),
+	(3. / 8., 0.6, 0.847059, 0.788235),
+	(4. / 8., 0.4, 0.760784, 0.643137),
+	(5. / 8., 0.254902, 0.682353, 0.462745),
+	(6. / 8., 0.137255, 0.545098, 0.270588),
+	(7. / 8., 0.0, 0.427451, 0.172549),
+	(1., 0.0, 0.266667, 0.105882),
+]);
+
+/// The ColorBrewer sequential `BuPu` palette
+pub const BU_PU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.988235, 0.992157),
+	(1. / 8., 0.878431, 0.92549, 0.956863),
+	(2. / 8., 0.74902, 0.827451, 0.901961),
+	(3. / 8., 0.619608, 0.737255, 0.854902),
+	(4. / 8., 0.54902, 0.588235, 0.776471),
+	(5. / 8., 0.54902, 0.419608, 0.694118),
+	(6. / 8., 0.533333, 0.254902, 0.615686),
+	(7. / 8., 0.505882, 0.058824, 0.486275),
+	(1., 0.301961, 0.0, 0.294118),
+]);
+
+/// The ColorBrewer sequential `GnBu` palette
+pub const GN_BU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.988235, 0.941176),
+	(1. / 8., 0.878431, 0.952941, 0.858824),
+	(2. / 8., 0.8, 0.921569, 0.772549),
+	(3. / 8., 0.658824, 0.866667, 0.709804),
+	(4. / 8., 0.482353, 0.8, 0.768627),
+	(5. / 8., 0.305882, 0.701961, 0.827451),
+	(6. / 8., 0.168627, 0.54902, 0.745098),
+	(7. / 8., 0.031373, 0.407843, 0.67451),
+	(1., 0.031373, 0.25098, 0.505882),
+]);
+
+/// The ColorBrewer sequential `Greens` palette
+pub const GREENS: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.988235, 0.960784),
+	(1. / 8., 0.898039, 0.960784, 0.878431),
+	(2. / 8., 0.780392, 0.913725, 0.752941),
+	(3. / 8., 0.631373, 0.85098, 0.607843),
+	(4. / 8., 0.454902, 0.768627, 0.462745),
+	(5. / 8., 0.254902, 0.670588, 0.364706),
+	(6. / 8., 0.137255, 0.545098, 0.270588),
+	(7. / 8., 0.0, 0.427451, 0.172549),
+	(1., 0.0, 0.266667, 0.105882),
+]);
+
+/// The ColorBrewer sequential `Greys` palette
+pub const GREYS: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 1.0, 1.0),
+	(1. / 8., 0.941176, 0.941176, 0.941176),
+	(2. / 8., 0.85098, 0.85098, 0.85098),
+	(3. / 8., 0.741176, 0.741176, 0.741176),
+	(4. / 8., 0.588235, 0.588235, 0.588235),
+	(5. / 8., 0.45098, 0.45098, 0.45098),
+	(6. / 8., 0.321569, 0.321569, 0.321569),
+	(7. / 8., 0.145098, 0.145098, 0.145098),
+	(1., 0.0, 0.0, 0.0),
+]);
+
+/// The ColorBrewer sequential `Oranges` palette
+pub const ORANGES: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.960784, 0.921569),
+	(1. / 8., 0.996078, 0.901961, 0.807843),
+	(2. / 8., 0.992157, 0.815686, 0.635294),
+	(3. / 8., 0.992157, 0.682353, 0.419608),
+	(4. / 8., 0.992157, 0.552941, 0.235294),
+	(5. / 8., 0.945098, 0.411765, 0.07451),
+	(6. / 8., 0.85098, 0.282353, 0.003922),
+	(7. / 8., 0.65098, 0.211765, 0.011765),
+	(1., 0.498039, 0.152941, 0.015686),
+]);
+
+/// The ColorBrewer sequential `OrRd` palette
+pub const OR_RD: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.968627, 0.92549),
+	(1. / 8., 0.996078, 0.909804, 0.784314),
+	(2. / 8., 0.992157, 0.831373, 0.619608),
+	(3. / 8., 0.992157, 0.733333, 0.517647),
+	(4. / 8., 0.988235, 0.552941, 0.34902),
+	(5. / 8., 0.937255, 0.396078, 0.282353),
+	(6. / 8., 0.843137, 0.188235, 0.121569),
+	(7. / 8., 0.701961, 0.0, 0.0),
+	(1., 0.498039, 0.0, 0.0),
+]);
+
+/// The ColorBrewer sequential `PuBu` palette
+pub const PU_BU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.968627, 0.984314),
+	(1. / 8., 0.92549, 0.905882, 0.94902),
+	(2. / 8., 0.815686, 0.819608, 0.901961),
+	(3. / 8., 0.65098, 0.741176, 0.858824),
+	(4. / 8., 0.454902, 0.662745, 0.811765),
+	(5. / 8., 0.211765, 0.564706, 0.752941),
+	(6. / 8., 0.019608, 0.439216, 0.690196),
+	(7. / 8., 0.015686, 0.352941, 0.552941),
+	(1., 0.007843, 0.219608, 0.345098),
+]);
+
+/// The ColorBrewer sequential `PuBuGn` palette
+pub const PU_BU_GN: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.968627, 0.984314),
+	(1. / 8., 0.92549, 0.886275, 0.941176),
+	(2. / 8., 0.815686, 0.819608, 0.901961),
+	(3. / 8., 0.65098, 0.741176, 0.858824),
+	(4. / 8., 0.403922, 0.662745, 0.811765),
+	(5. / 8., 0.211765, 0.564706, 0.752941),
+	(6. / 8., 0.007843, 0.505882, 0.541176),
+	(7. / 8., 0.003922, 0.423529, 0.34902),
+	(1., 0.003922, 0.27451, 0.211765),
+]);
+
+/// The ColorBrewer sequential `PuRd` palette
+pub const PU_RD: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.968627, 0.956863, 0.976471),
+	(1. / 8., 0.905882, 0.882353, 0.937255),
+	(2. / 8., 0.831373, 0.72549, 0.854902),
+	(3. / 8., 0.788235, 0.580392, 0.780392),
+	(4. / 8., 0.87451, 0.396078, 0.690196),
+	(5. / 8., 0.905882, 0.160784, 0.541176),
+	(6. / 8., 0.807843, 0.070588, 0.337255),
+	(7. / 8., 0.596078, 0.0, 0.262745),
+	(1., 0.403922, 0.0, 0.121569),
+]);
+
+/// The ColorBrewer sequential `Purples` palette
+pub const PURPLES: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.988235, 0.984314, 0.992157),
+	(1. / 8., 0.937255, 0.929412, 0.960784),
+	(2. / 8., 0.854902, 0.854902, 0.921569),
+	(3. / 8., 0.737255, 0.741176, 0.862745),
+	(4. / 8., 0.619608, 0.603922, 0.784314),
+	(5. / 8., 0.501961, 0.490196, 0.729412),
+	(6. / 8., 0.415686, 0.317647, 0.639216),
+	(7. / 8., 0.329412, 0.152941, 0.560784),
+	(1., 0.247059, 0.0, 0.490196),
+]);
+
+/// The ColorBrewer sequential `RdPu` palette
+pub const RD_PU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.968627, 0.952941),
+	(1. / 8., 0.992157, 0.878431, 0.866667),
+	(2. / 8., 0.988235, 0.772549, 0.752941),
+	(3. / 8., 0.980392, 0.623529, 0.709804),
+	(4. / 8., 0.968627, 0.407843, 0.631373),
+	(5. / 8., 0.866667, 0.203922, 0.592157),
+	(6. / 8., 0.682353, 0.003922, 0.494118),
+	(7. / 8., 0.478431, 0.003922, 0.466667),
+	(1., 0.286275, 0.0, 0.415686),
+]);
+
+/// The ColorBrewer sequential `Reds` palette
+pub const REDS: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 0.960784, 0.941176),
+	(1. / 8., 0.996078, 0.878431, 0.823529),
+	(2. / 8., 0.988235, 0.733333, 0.631373),
+	(3. / 8., 0.988235, 0.572549, 0.447059),
+	(4. / 8., 0.984314, 0.415686, 0.290196),
+	(5. / 8., 0.937255, 0.231373, 0.172549),
+	(6. / 8., 0.796078, 0.094118, 0.113725),
+	(7. / 8., 0.647059, 0.058824, 0.082353),
+	(1., 0.403922, 0.0, 0.05098),
+]);
+
+/// The ColorBrewer sequential `YlGn` palette
+pub const YL_GN: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 1.0, 0.898039),
+	(1. / 8., 0.968627, 0.988235, 0.72549),
+	(2. / 8., 0.85098, 0.941176, 0.639216),
+	(3. / 8., 0.678431, 0.866667, 0.556863),
+	(4. / 8., 0.470588, 0.776471, 0.47451),
+	(5. / 8., 0.254902, 0.670588, 0.364706),
+	(6. / 8., 0.137255, 0.517647, 0.262745),
+	(7. / 8., 0.0, 0.407843, 0.215686),
+	(1., 0.0, 0.270588, 0.160784),
+]);
+
+/// The ColorBrewer sequential `YlGnBu` palette
+pub const YL_GN_BU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 1.0, 0.85098),
+	(1. / 8., 0.929412, 0.972549, 0.694118),
+	(2. / 8., 0.780392, 0.913725, 0.705882),
+	(3. / 8., 0.498039, 0.803922, 0.733333),
+	(4. / 8., 0.254902, 0.713725, 0.768627),
+	(5. / 8., 0.113725, 0.568627, 0.752941),
+	(6. / 8., 0.133333, 0.368627, 0.658824),
+	(7. / 8., 0.145098, 0.203922, 0.580392),
+	(1., 0.031373, 0.113725, 0.345098),
+]);
+
+/// The ColorBrewer sequential `YlOrBr` palette
+pub const YL_OR_BR: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 1.0, 0.898039),
+	(1. / 8., 1.0, 0.968627, 0.737255),
+	(2. / 8., 0.996078, 0.890196, 0.568627),
+	(3. / 8., 0.996078, 0.768627, 0.309804),
+	(4. / 8., 0.996078, 0.6, 0.160784),
+	(5. / 8., 0.92549, 0.439216, 0.078431),
+	(6. / 8., 0.8, 0.298039, 0.007843),
+	(7. / 8., 0.6, 0.203922, 0.015686),
+	(1., 0.4, 0.145098, 0.023529),
+]);
+
+/// The ColorBrewer sequential `YlOrRd` palette
+pub const YL_OR_RD: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 1.0, 1.0, 0.8),
+	(1. / 8., 1.0, 0.929412, 0.627451),
+	(2. / 8., 0.996078, 0.85098, 0.462745),
+	(3. / 8., 0.996078, 0.698039, 0.298039),
+	(4. / 8., 0.992157, 0.552941, 0.235294),
+	(5. / 8., 0.988235, 0.305882, 0.164706),
+	(6. / 8., 0.890196, 0.101961, 0.109804),
+	(7. / 8., 0.741176, 0.0, 0.14902),
+	(1., 0.501961, 0.0, 0.14902),
+]);
+
+/// The ColorBrewer diverging `BrBG` palette
+pub const BR_BG: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.329412, 0.188235, 0.019608),
+	(1. / 10., 0.54902, 0.317647, 0.039216),
+	(2. / 10., 0.74902, 0.505882, 0.176471),
+	(3. / 10., 0.87451, 0.760784, 0.490196),
+	(4. / 10., 0.964706, 0.909804, 0.764706),
+	(5. / 10., 0.960784, 0.960784, 0.960784),
+	(6. / 10., 0.780392, 0.917647, 0.898039),
+	(7. / 10., 0.501961, 0.803922, 0.756863),
+	(8. / 10., 0.207843, 0.592157, 0.560784),
+	(9. / 10., 0.003922, 0.4, 0.368627),
+	(1., 0.0, 0.235294, 0.188235),
+]);
+
+/// The ColorBrewer diverging `PiYG` palette
+pub const PI_YG: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.556863, 0.003922, 0.321569),
+	(1. / 10., 0.772549, 0.105882, 0.490196),
+	(2. / 10., 0.870588, 0.466667, 0.682353),
+	(3. / 10., 0.945098, 0.713725, 0.854902),
+	(4. / 10., 0.992157, 0.878431, 0.937255),
+	(5. / 10., 0.968627, 0.968627, 0.968627),
+	(6. / 10., 0.901961, 0.960784, 0.815686),
+	(7. / 10., 0.721569, 0.882353, 0.52549),
+	(8. / 10., 0.498039, 0.737255, 0.254902),
+	(9. / 10., 0.301961, 0.572549, 0.129412),
+	(1., 0.152941, 0.392157, 0.098039),
+]);
+
+/// The ColorBrewer diverging `PRGn` palette
+pub const PR_GN: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.25098, 0.0, 0.294118),
+	(1. / 10., 0.462745, 0.164706, 0.513725),
+	(2. / 10., 0.6, 0.439216, 0.670588),
+	(3. / 10., 0.760784, 0.647059, 0.811765),
+	(4. / 10., 0.905882, 0.831373, 0.909804),
+	(5. / 10., 0.968627, 0.968627, 0.968627),
+	(6. / 10., 0.85098, 0.941176, 0.827451),
+	(7. / 10., 0.65098, 0.858824, 0.627451),
+	(8. / 10., 0.352941, 0.682353, 0.380392),
+	(9. / 10., 0.105882, 0.470588, 0.215686),
+	(1., 0.0, 0.266667, 0.105882),
+]);
+
+/// The ColorBrewer diverging `PuOr` palette
+pub const PU_OR: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.498039, 0.231373, 0.031373),
+	(1. / 10., 0.701961, 0.345098, 0.023529),
+	(2. / 10., 0.878431, 0.509804, 0.078431),
+	(3. / 10., 0.992157, 0.721569, 0.388235),
+	(4. / 10., 0.996078, 0.878431, 0.713725),
+	(5. / 10., 0.968627, 0.968627, 0.968627),
+	(6. / 10., 0.847059, 0.854902, 0.921569),
+	(7. / 10., 0.698039, 0.670588, 0.823529),
+	(8. / 10., 0.501961, 0.45098, 0.67451),
+	(9. / 10., 0.329412, 0.152941, 0.533333),
+	(1., 0.176471, 0.0, 0.294118),
+]);
+
+/// The ColorBrewer diverging `RdBu` palette
+pub const RD_BU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.403922, 0.0, 0.121569),
+	(1. / 10., 0.698039, 0.094118, 0.168627),
+	(2. / 10., 0.839216, 0.376471, 0.301961),
+	(3. / 10., 0.956863, 0.647059, 0.509804),
+	(4. / 10., 0.992157, 0.858824, 0.780392),
+	(5. / 10., 0.968627, 0.968627, 0.968627),
+	(6. / 10., 0.819608, 0.898039, 0.941176),
+	(7. / 10., 0.572549, 0.772549, 0.870588),
+	(8. / 10., 0.262745, 0.576471, 0.764706),
+	(9. / 10., 0.129412, 0.4, 0.67451),
+	(1., 0.019608, 0.188235, 0.380392),
+]);
+
+/// The ColorBrewer diverging `RdGy` palette
+pub const RD_GY: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.403922, 0.0, 0.121569),
+	(1. / 10., 0.698039, 0.094118, 0.168627),
+	(2. / 10., 0.839216, 0.376471, 0.301961),
+	(3. / 10., 0.956863, 0.647059, 0.509804),
+	(4. / 10., 0.992157, 0.858824, 0.780392),
+	(5. / 10., 1.0, 1.0, 1.0),
+	(6. / 10., 0.878431, 0.878431, 0.878431),
+	(7. / 10., 0.729412, 0.729412, 0.729412),
+	(8. / 10., 0.529412, 0.529412, 0.529412),
+	(9. / 10., 0.301961, 0.301961, 0.301961),
+	(1., 0.101961, 0.101961, 0.101961),
+]);
+
+/// The ColorBrewer diverging `RdYlBu` palette
+pub const RD_YL_BU: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.647059, 0.0, 0.14902),
+	(1. / 10., 0.843137, 0.188235, 0.152941),
+	(2. / 10., 0.956863, 0.427451, 0.262745),
+	(3. / 10., 0.992157, 0.682353, 0.380392),
+	(4. / 10., 0.996078, 0.878431, 0.564706),
+	(5. / 10., 1.0, 1.0, 0.74902),
+	(6. / 10., 0.878431, 0.952941, 0.972549),
+	(7. / 10., 0.670588, 0.85098, 0.913725),
+	(8. / 10., 0.454902, 0.678431, 0.819608),
+	(9. / 10., 0.270588, 0.458824, 0.705882),
+	(1., 0.192157, 0.211765, 0.584314),
+]);
+
+/// The ColorBrewer diverging `RdYlGn` palette
+pub const RD_YL_GN: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.647059, 0.0, 0.14902),
+	(1. / 10., 0.843137, 0.188235, 0.152941),
+	(2. / 10., 0.956863, 0.427451, 0.262745),
+	(3. / 10., 0.992157, 0.682353, 0.380392),
+	(4. / 10., 0.996078, 0.878431, 0.545098),
+	(5. / 10., 1.0, 1.0, 0.74902),
+	(6. / 10., 0.85098, 0.937255, 0.545098),
+	(7. / 10., 0.65098, 0.85098, 0.415686),
+	(8. / 10., 0.4, 0.741176, 0.388235),
+	(9. / 10., 0.101961, 0.596078, 0.313725),
+	(1., 0.0, 0.407843, 0.215686),
+]);
+
+/// The ColorBrewer diverging `Spectral` palette
+pub const SPECTRAL: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.619608, 0.003922, 0.258824),
+	(1. / 10., 0.835294, 0.243137, 0.309804),
+	(2. / 10., 0.956863, 0.427451, 0.262745),
+	(3. / 10., 0.992157, 0.682353, 0.380392),
+	(4. / 10., 0.996078, 0.878431, 0.545098),
+	(5. / 10., 1.0, 1.0, 0.74902),
+	(6. / 10., 0.901961, 0.960784, 0.596078),
+	(7. / 10., 0.670588, 0.866667, 0.643137),
+	(8. / 10., 0.4, 0.760784, 0.647059),
+	(9. / 10., 0.196078, 0.533333, 0.741176),
+	(1., 0.368627, 0.309804, 0.635294),
+]);
+
+/// The ColorBrewer qualitative `Accent` palette
+pub const ACCENT: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.498039, 0.788235, 0.498039),
+	(1. / 8., 0.498039, 0.788235, 0.498039),
+	(1. / 8., 0.745098, 0.682353, 0.831373),
+	(2. / 8., 0.745098, 0.682353, 0.831373),
+	(2. / 8., 0.992157, 0.752941, 0.52549),
+	(3. / 8., 0.992157, 0.752941, 0.52549),
+	(3. / 8., 1.0, 1.0, 0.6),
+	(4. / 8., 1.0, 1.0, 0.6),
+	(4. / 8., 0.219608, 0.423529, 0.690196),
+	(5. / 8., 0.219608, 0.423529, 0.690196),
+	(5. / 8., 0.941176, 0.007843, 0.498039),
+	(6. / 8., 0.941176, 0.007843, 0.498039),
+	(6. / 8., 0.74902, 0.356863, 0.090196),
+	(7. / 8., 0.74902, 0.356863, 0.090196),
+	(7. / 8., 0.4, 0.4, 0.4),
+	(1., 0.4, 0.4, 0.4),
+]);
+
+/// The ColorBrewer qualitative `Dark2` palette
+pub const DARK2: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.105882, 0.619608, 0.466667),
+	(1. / 8., 0.105882, 0.619608, 0.466667),
+	(1. / 8., 0.85098, 0.372549, 0.007843),
+	(2. / 8., 0.85098, 0.372549, 0.007843),
+	(2. / 8., 0.458824, 0.439216, 0.701961),
+	(3. / 8., 0.458824, 0.439216, 0.701961),
+	(3. / 8., 0.905882, 0.160784, 0.541176),
+	(4. / 8., 0.905882, 0.160784, 0.541176),
+	(4. / 8., 0.4, 0.65098, 0.117647),
+	(5. / 8., 0.4, 0.65098, 0.117647),
+	(5. / 8., 0.901961, 0.670588, 0.007843),
+	(6. / 8., 0.901961, 0.670588, 0.007843),
+	(6. / 8., 0.65098, 0.462745, 0.113725),
+	(7. / 8., 0.65098, 0.462745, 0.113725),
+	(7. / 8., 0.4, 0.4, 0.4),
+	(1., 0.4, 0.4, 0.4),
+]);
+
+/// The ColorBrewer qualitative `Paired` palette
+pub const PAIRED: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.65098, 0.807843, 0.890196),
+	(1. / 12., 0.65098, 0.807843, 0.890196),
+	(1. / 12., 0.121569, 0.470588, 0.705882),
+	(2. / 12., 0.121569, 0.470588, 0.705882),
+	(2. / 12., 0.698039, 0.87451, 0.541176),
+	(3. / 12., 0.698039, 0.87451, 0.541176),
+	(3. / 12., 0.2, 0.627451, 0.172549),
+	(4. / 12., 0.2, 0.627451, 0.172549),
+	(4. / 12., 0.984314, 0.603922, 0.6),
+	(5. / 12., 0.984314, 0.603922, 0.6),
+	(5. / 12., 0.890196, 0.101961, 0.109804),
+	(6. / 12., 0.890196, 0.101961, 0.109804),
+	(6. / 12., 0.992157, 0.74902, 0.435294),
+	(7. / 12., 0.992157, 0.74902, 0.435294),
+	(7. / 12., 1.0, 0.498039, 0.0),
+	(8. / 12., 1.0, 0.498039, 0.0),
+	(8. / 12., 0.792157, 0.698039, 0.839216),
+	(9. / 12., 0.792157, 0.698039, 0.839216),
+	(9. / 12., 0.415686, 0.239216, 0.603922),
+	(10. / 12., 0.415686, 0.239216, 0.603922),
+	(10. / 12., 1.0, 1.0, 0.6),
+	(11. / 12., 1.0, 1.0, 0.6),
+	(11. / 12., 0.694118, 0.34902, 0.156863),
+	(1., 0.694118, 0.34902, 0.156863),
+]);
+
+/// The ColorBrewer qualitative `Pastel1` palette
+pub const PASTEL1: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.984314, 0.705882, 0.682353),
+	(1. / 9., 0.984314, 0.705882, 0.682353),
+	(1. / 9., 0.701961, 0.803922, 0.890196),
+	(2. / 9., 0.701961, 0.803922, 0.890196),
+	(2. / 9., 0.8, 0.921569, 0.772549),
+	(3. / 9., 0.8, 0.921569, 0.772549),
+	(3. / 9., 0.870588, 0.796078, 0.894118),
+	(4. / 9., 0.870588, 0.796078, 0.894118),
+	(4. / 9., 0.996078, 0.85098, 0.65098),
+	(5. / 9., 0.996078, 0.85098, 0.65098),
+	(5. / 9., 1.0, 1.0, 0.8),
+	(6. / 9., 1.0, 1.0, 0.8),
+	(6. / 9., 0.898039, 0.847059, 0.741176),
+	(7. / 9., 0.898039, 0.847059, 0.741176),
+	(7. / 9., 0.992157, 0.854902, 0.92549),
+	(8. / 9., 0.992157, 0.854902, 0.92549),
+	(8. / 9., 0.94902, 0.94902, 0.94902),
+	(1., 0.94902, 0.94902, 0.94902),
+]);
+
+/// The ColorBrewer qualitative `Pastel2` palette
+pub const PASTEL2: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.701961, 0.886275, 0.803922),
+	(1. / 8., 0.701961, 0.886275, 0.803922),
+	(1. / 8., 0.992157, 0.803922, 0.67451),
+	(2. / 8., 0.992157, 0.803922, 0.67451),
+	(2. / 8., 0.796078, 0.835294, 0.909804),
+	(3. / 8., 0.796078, 0.835294, 0.909804),
+	(3. / 8., 0.956863, 0.792157, 0.894118),
+	(4. / 8., 0.956863, 0.792157, 0.894118),
+	(4. / 8., 0.901961, 0.960784, 0.788235),
+	(5. / 8., 0.901961, 0.960784, 0.788235),
+	(5. / 8., 1.0, 0.94902, 0.682353),
+	(6. / 8., 1.0, 0.94902, 0.682353),
+	(6. / 8., 0.945098, 0.886275, 0.8),
+	(7. / 8., 0.945098, 0.886275, 0.8),
+	(7. / 8., 0.8, 0.8, 0.8),
+	(1., 0.8, 0.8, 0.8),
+]);
+
+/// The ColorBrewer qualitative `Set1` palette
+pub const SET1: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.894118, 0.101961, 0.109804),
+	(1. / 9., 0.894118, 0.101961, 0.109804),
+	(1. / 9., 0.215686, 0.494118, 0.721569),
+	(2. / 9., 0.215686, 0.494118, 0.721569),
+	(2. / 9., 0.301961, 0.686275, 0.290196),
+	(3. / 9., 0.301961, 0.686275, 0.290196),
+	(3. / 9., 0.596078, 0.305882, 0.639216),
+	(4. / 9., 0.596078, 0.305882, 0.639216),
+	(4. / 9., 1.0, 0.498039, 0.0),
+	(5. / 9., 1.0, 0.498039, 0.0),
+	(5. / 9., 1.0, 1.0, 0.2),
+	(6. / 9., 1.0, 1.0, 0.2),
+	(6. / 9., 0.65098, 0.337255, 0.156863),
+	(7. / 9., 0.65098, 0.337255, 0.156863),
+	(7. / 9., 0.968627, 0.505882, 0.74902),
+	(8. / 9., 0.968627, 0.505882, 0.74902),
+	(8. / 9., 0.6, 0.6, 0.6),
+	(1., 0.6, 0.6, 0.6),
+]);
+
+/// The ColorBrewer qualitative `Set2` palette
+pub const SET2: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.4, 0.760784, 0.647059),
+	(1. / 8., 0.4, 0.760784, 0.647059),
+	(1. / 8., 0.988235, 0.552941, 0.384314),
+	(2. / 8., 0.988235, 0.552941, 0.384314),
+	(2. / 8., 0.552941, 0.627451, 0.796078),
+	(3. / 8., 0.552941, 0.627451, 0.796078),
+	(3. / 8., 0.905882, 0.541176, 0.764706),
+	(4. / 8., 0.905882, 0.541176, 0.764706),
+	(4. / 8., 0.65098, 0.847059, 0.329412),
+	(5. / 8., 0.65098, 0.847059, 0.329412),
+	(5. / 8., 1.0, 0.85098, 0.184314),
+	(6. / 8., 1.0, 0.85098, 0.184314),
+	(6. / 8., 0.898039, 0.768627, 0.580392),
+	(7. / 8., 0.898039, 0.768627, 0.580392),
+	(7. / 8., 0.701961, 0.701961, 0.701961),
+	(1., 0.701961, 0.701961, 0.701961),
+]);
+
+/// The ColorBrewer qualitative `Set3` palette
+pub const SET3: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.552941, 0.827451, 0.780392),
+	(1. / 12., 0.552941, 0.827451, 0.780392),
+	(1. / 12., 1.0, 1.0, 0.701961),
+	(2. / 12., 1.0, 1.0, 0.701961),
+	(2. / 12., 0.745098, 0.729412, 0.854902),
+	(3. / 12., 0.745098, 0.729412, 0.854902),
+	(3. / 12., 0.984314, 0.501961, 0.447059),
+	(4. / 12., 0.984314, 0.501961, 0.447059),
+	(4. / 12., 0.501961, 0.694118, 0.827451),
+	(5. / 12., 0.501961, 0.694118, 0.827451),
+	(5. / 12., 0.992157, 0.705882, 0.384314),
+	(6. / 12., 0.992157, 0.705882, 0.384314),
+	(6. / 12., 0.701961, 0.870588, 0.411765),
+	(7. / 12., 0.701961, 0.870588, 0.411765),
+	(7. / 12., 0.988235, 0.803922, 0.898039),
+	(8. / 12., 0.988235, 0.803922, 0.898039),
+	(8. / 12., 0.85098, 0.85098, 0.85098),
+	(9. / 12., 0.85098, 0.85098, 0.85098),
+	(9. / 12., 0.737255, 0.501961, 0.741176),
+	(10. / 12., 0.737255, 0.501961, 0.741176),
+	(10. / 12., 0.8, 0.921569, 0.772549),
+	(11. / 12., 0.8, 0.921569, 0.772549),
+	(11. / 12., 1.0, 0.929412, 0.435294),
+	(1., 1.0, 0.929412, 0.435294),
+]);
@@ -3,5 +3,207 @@
 // All rights reserved. Distributed under LGPL 3.0. For full terms see the file LICENSE.
 
 mod cm_listed;
+mod colorbrewer;
 
 pub use cm_listed::*;
+pub use colorbrewer::*;
+
+use crate::color::ColorType;
+use crate::options::*;
+use crate::util::OneWayOwned;
+use std::fmt::{Debug, Display};
+
+/// A grayscale palette with lightness (CIE L*) increasing linearly from black to white
+pub const GRAY_UNIFORM: PaletteType<&'static [(f32, f32, f32, f32)]> = PaletteType::Custom(&[
+	(0., 0.0, 0.0, 0.0),
+	(1. / 16., 0.077823, 0.077823, 0.077823),
+	(2. / 16., 0.127489, 0.127489, 0.127489),
+	(3. / 16., 0.178816, 0.178816, 0.178816),
+	(4. / 16., 0.232515, 0.232515, 0.232515),
+	(5. / 16., 0.288307, 0.288307, 0.288307),
+	(6. / 16., 0.345980, 0.345980, 0.345980),
+	(7. / 16., 0.405365, 0.405365, 0.405365),
+	(8. / 16., 0.466327, 0.466327, 0.466327),
+	(9. / 16., 0.528751, 0.528751, 0.528751),
+	(10. / 16., 0.592541, 0.592541, 0.592541),
+	(11. / 16., 0.657614, 0.657614, 0.657614),
+	(12. / 16., 0.723900, 0.723900, 0.723900),
+	(13. / 16., 0.791334, 0.791334, 0.791334),
+	(14. / 16., 0.859862, 0.859862, 0.859862),
+	(15. / 16., 0.929432, 0.929432, 0.929432),
+	(1., 1.0, 1.0, 1.0),
+]);
+
+type StaticPalette = PaletteType<&'static [(f32, f32, f32, f32)]>;
+type OwnedPalette = PaletteType<Vec<(f32, f32, f32, f32)>>;
+
+/// Palettes that can be looked up by name with `by_name`
+const NAMED_PALETTES: &[(&str, StaticPalette)] = &[
+	("gray", GRAY),
+	("color", COLOR),
+	("rainbow", RAINBOW),
+	("hot", HOT),
+	("helix", HELIX),
+	("gray_uniform", GRAY_UNIFORM),
+	("magma", MAGMA),
+	("inferno", INFERNO),
+	("plasma", PLASMA),
+	("viridis", VIRIDIS),
+	("cividis", CIVIDIS),
+	("twilight", TWILIGHT),
+	("twilight_shifted", TWILIGHT_SHIFTED),
+	("turbo", TURBO),
+	("Blues", BLUES),
+	("BuGn", BU_GN),
+	("BuPu", BU_PU),
+	("GnBu", GN_BU),
+	("Greens", GREENS),
+	("Greys", GREYS),
+	("Oranges", ORANGES),
+	("OrRd", OR_RD),
+	("PuBu", PU_BU),
+	("PuBuGn", PU_BU_GN),
+	("PuRd", PU_RD),
+	("Purples", PURPLES),
+	("RdPu", RD_PU),
+	("Reds", REDS),
+	("YlGn", YL_GN),
+	("YlGnBu", YL_GN_BU),
+	("YlOrBr", YL_OR_BR),
+	("YlOrRd", YL_OR_RD),
+	("BrBG", BR_BG),
+	("PiYG", PI_YG),
+	("PRGn", PR_GN),
+	("PuOr", PU_OR),
+	("RdBu", RD_BU),
+	("RdGy", RD_GY),
+	("RdYlBu", RD_YL_BU),
+	("RdYlGn", RD_YL_GN),
+	("Spectral", SPECTRAL),
+	("Accent", ACCENT),
+	("Dark2", DARK2),
+	("Paired", PAIRED),
+	("Pastel1", PASTEL1),
+	("Pastel2", PASTEL2),
+	("Set1", SET1),
+	("Set2", SET2),
+	("Set3", SET3),
+];
+
+/// Returns the names of all the palettes that can be looked up with `by_name`
+pub fn palette_names() -> impl Iterator<Item = &'static str>
+{
+	NAMED_PALETTES.iter().map(|&(name, _)| name)
+}
+
+/// Looks up a palette by its name (see `palette_names`), ignoring case, e.g. `"viridis"` or
+/// `"RdBu"`. Appending `_r` to the name returns the reversed palette, which is only available for
+/// palettes defined by a table (i.e. not `gray`, `color`, `rainbow`, `hot` and `helix`).
+///
+/// The result can be passed to `AxesCommon::set_palette` via `PaletteType::to_ref`.
+pub fn by_name(name: &str) -> Option<OwnedPalette>
+{
+	let find = |name: &str| {
+		NAMED_PALETTES
+			.iter()
+			.find(|&&(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, palette)| palette.to_one_way_owned())
+	};
+	find(name).or_else(|| {
+		name.strip_suffix("_r")
+			.or_else(|| name.strip_suffix("_R"))
+			.and_then(find)
+			.and_then(|palette| palette.reversed())
+	})
+}
+
+fn palette_rgb<T: Display + Debug>(color: &ColorType<T>) -> (f32, f32, f32)
+{
+	let (r, g, b) = color
+		.rgb()
+		.unwrap_or_else(|| panic!("Cannot use {:?} in a palette", color));
+	(r as f32 / 255., g as f32 / 255., b as f32 / 255.)
+}
+
+/// Creates a table for a `Custom` palette made of bands of uniform color with sharp boundaries
+/// between them, e.g. for classified rasters or categorical heatmaps. Each color occupies an equal
+/// fraction of the color range, so if the color range is set to `(-0.5, n - 0.5)` the integer value
+/// `i` picks out the `i`th color.
+///
+/// Use it together with `AxesCommon::set_palette_max_colors` to make sure the terminal does not
+/// smooth out the boundaries.
+///
+/// # Arguments
+/// * `colors` - The colors of the bands. Only fixed colors are supported, i.e. not the palette or
+///   variable ones.
+pub fn stepped_palette<T: Display + Debug>(colors: &[ColorType<T>]) -> Vec<(f32, f32, f32, f32)>
+{
+	assert!(
+		!colors.is_empty(),
+		"Need at least one color in a stepped palette"
+	);
+	let n = colors.len() as f32;
+	colors
+		.iter()
+		.enumerate()
+		.flat_map(|(i, color)| {
+			let (r, g, b) = palette_rgb(color);
+			// Duplicate positions make gnuplot switch colors abruptly.
+			[(i as f32 / n, r, g, b), ((i + 1) as f32 / n, r, g, b)]
+		})
+		.collect()
+}
+
+/// Creates a table for a `Custom` palette that smoothly interpolates between evenly spaced colors.
+///
+/// # Arguments
+/// * `colors` - The colors, at least two. Only fixed colors are supported, i.e. not the palette or
+///   variable ones.
+pub fn gradient_palette<T: Display + Debug>(colors: &[ColorType<T>]) -> Vec<(f32, f32, f32, f32)>
+{
+	assert!(
+		colors.len() >= 2,
+		"Need at least two colors in a gradient palette"
+	);
+	let n = (colors.len() - 1) as f32;
+	colors
+		.iter()
+		.enumerate()
+		.map(|(i, color)| {
+			let (r, g, b) = palette_rgb(color);
+			(i as f32 / n, r, g, b)
+		})
+		.collect()
+}
+
+#[test]
+fn by_name_test()
+{
+	assert_eq!(by_name("RDBU"), Some(RD_BU.to_one_way_owned()));
+	assert_eq!(by_name("viridis_r"), VIRIDIS.to_one_way_owned().reversed());
+	assert_eq!(by_name("hot_r"), None);
+	assert_eq!(by_name("no_such_palette"), None);
+	for name in palette_names()
+	{
+		assert!(by_name(name).is_some());
+	}
+}
+
+#[test]
+fn stepped_palette_test()
+{
+	let colors: [ColorType; 2] = ["red".into(), "#0000ff".into()];
+	assert_eq!(
+		stepped_palette(&colors),
+		vec![
+			(0.0, 1.0, 0.0, 0.0),
+			(0.5, 1.0, 0.0, 0.0),
+			(0.5, 0.0, 0.0, 1.0),
+			(1.0, 0.0, 0.0, 1.0)
+		]
+	);
+	assert_eq!(
+		gradient_palette(&[ColorType::<&str>::RGBInteger(0, 255, 0), ColorType::Black]),
+		vec![(0.0, 0.0, 1.0, 0.0), (1.0, 0.0, 0.0, 0.0)]
+	);
+}
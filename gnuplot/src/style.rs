@@ -0,0 +1,726 @@
+// Copyright (c) 2013-2014 by SiegeLord
+//
+// All rights reserved. Distributed under LGPL 3.0. For full terms see the file LICENSE.
+
+use crate::color::ColorType;
+#[cfg(feature = "toml")]
+use crate::error_types::ThemeError;
+use crate::options::*;
+use crate::util::{escape, OneWayOwned};
+use crate::writer::Writer;
+#[cfg(feature = "toml")]
+use std::mem;
+
+/// Cycles of styles given to successive plot elements (lines, points, error bars and filled
+/// curves) that don't specify them explicitly, see `AxesCommon::set_style_cycle`.
+///
+/// Each property cycles independently, so the combined styles repeat with a period equal to the least
+/// common multiple of the cycle lengths, e.g. 6 for 3 colors and 2 dash types, but 4 for 4 colors
+/// and 2 dash types.
+/// Properties with an empty cycle are left to gnuplot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyleCycle
+{
+	colors: Vec<ColorType>,
+	dash_types: Vec<DashType>,
+	point_symbols: Vec<char>,
+	line_widths: Vec<f64>,
+}
+
+impl Default for StyleCycle
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl StyleCycle
+{
+	/// Creates a style cycle that leaves all the styles to gnuplot
+	pub fn new() -> Self
+	{
+		StyleCycle {
+			colors: vec![],
+			dash_types: vec![],
+			point_symbols: vec![],
+			line_widths: vec![],
+		}
+	}
+
+	/// The colors of the Tableau 10 palette, used by default in many other plotting libraries
+	pub fn tableau10() -> Self
+	{
+		let mut cycle = Self::new();
+		cycle.set_colors(&[
+			preset_color("#1f77b4"),
+			preset_color("#ff7f0e"),
+			preset_color("#2ca02c"),
+			preset_color("#d62728"),
+			preset_color("#9467bd"),
+			preset_color("#8c564b"),
+			preset_color("#e377c2"),
+			preset_color("#7f7f7f"),
+			preset_color("#bcbd22"),
+			preset_color("#17becf"),
+		]);
+		cycle
+	}
+
+	/// The colors of the Okabe-Ito palette, which are distinguishable with all common forms of color
+	/// blindness
+	pub fn okabe_ito() -> Self
+	{
+		let mut cycle = Self::new();
+		cycle.set_colors(&[
+			preset_color("#e69f00"),
+			preset_color("#56b4e9"),
+			preset_color("#009e73"),
+			preset_color("#f0e442"),
+			preset_color("#0072b2"),
+			preset_color("#d55e00"),
+			preset_color("#cc79a7"),
+			preset_color("#000000"),
+		]);
+		cycle
+	}
+
+	/// Shades of gray combined with dash types and point symbols, for figures printed in black and
+	/// white
+	pub fn grayscale() -> Self
+	{
+		let mut cycle = Self::new();
+		cycle
+			.set_colors(&[
+				preset_color("#000000"),
+				preset_color("#555555"),
+				preset_color("#999999"),
+			])
+			.set_dash_types(&[Solid, Dash, Dot, DotDash])
+			.set_point_symbols(&['O', 'S', 'T', 'D', 'x']);
+		cycle
+	}
+
+	/// Sets the colors to cycle through
+	pub fn set_colors(&mut self, colors: &[ColorType<&str>]) -> &mut Self
+	{
+		self.colors = colors.iter().map(|c| c.to_one_way_owned()).collect();
+		self
+	}
+
+	/// Sets the dash types of lines to cycle through
+	pub fn set_dash_types(&mut self, dash_types: &[DashType]) -> &mut Self
+	{
+		self.dash_types = dash_types.to_vec();
+		self
+	}
+
+	/// Sets the point symbols to cycle through, see `PointSymbol` for the valid symbols
+	pub fn set_point_symbols(&mut self, point_symbols: &[char]) -> &mut Self
+	{
+		self.point_symbols = point_symbols.to_vec();
+		self
+	}
+
+	/// Sets the line widths to cycle through
+	pub fn set_line_widths(&mut self, line_widths: &[f64]) -> &mut Self
+	{
+		self.line_widths = line_widths.to_vec();
+		self
+	}
+
+	/// Returns the styles of the `index`th plot element
+	pub(crate) fn options(&self, index: usize) -> Vec<PlotOption<String>>
+	{
+		let mut options = vec![];
+		if !self.colors.is_empty()
+		{
+			options.push(Color(self.colors[index % self.colors.len()].clone()));
+		}
+		if !self.dash_types.is_empty()
+		{
+			options.push(LineStyle(
+				self.dash_types[index % self.dash_types.len()].clone(),
+			));
+		}
+		if !self.point_symbols.is_empty()
+		{
+			options.push(PointSymbol(
+				self.point_symbols[index % self.point_symbols.len()],
+			));
+		}
+		if !self.line_widths.is_empty()
+		{
+			options.push(LineWidth(self.line_widths[index % self.line_widths.len()]));
+		}
+		options
+	}
+}
+
+/// Tag of the object used to draw the figure background. Chosen to not collide with the tags of
+/// the objects added to the axes.
+const BACKGROUND_TAG: i32 = i32::MAX;
+
+/// A set of default styles applied to every axes of a figure, see `Figure::set_theme`.
+///
+/// Properties left unset keep the gnuplot defaults. Settings made on individual axes (e.g. via
+/// `set_border`, `set_grid_options` or the `TextColor` label option) take precedence over the
+/// theme.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme
+{
+	font_family: Option<String>,
+	font_size: Option<f64>,
+	background: Option<ColorType>,
+	text_color: Option<ColorType>,
+	show_border: Option<bool>,
+	border_options: Vec<PlotOption<String>>,
+	show_grid: Option<bool>,
+	grid_options: Vec<PlotOption<String>>,
+	ticks_inward: Option<bool>,
+	legend_box: Option<bool>,
+	legend_opaque: Option<bool>,
+}
+
+impl Default for Theme
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl Theme
+{
+	/// Creates a theme that leaves all the styles to gnuplot
+	pub fn new() -> Self
+	{
+		Theme {
+			font_family: None,
+			font_size: None,
+			background: None,
+			text_color: None,
+			show_border: None,
+			border_options: vec![],
+			show_grid: None,
+			grid_options: vec![],
+			ticks_inward: None,
+			legend_box: None,
+			legend_opaque: None,
+		}
+	}
+
+	/// Black text and border on a white background with a light gray grid
+	pub fn light() -> Self
+	{
+		let mut theme = Self::new();
+		theme
+			.set_background(preset_color("white"))
+			.set_text_color(preset_color("black"))
+			.set_border(true, &[Color(preset_color("black"))])
+			.set_grid(true, &[Color(preset_color("#d0d0d0")), LineStyle(Solid)])
+			.set_legend_style(false, false);
+		theme
+	}
+
+	/// Light text and border on a dark background with a subdued dotted grid
+	pub fn dark() -> Self
+	{
+		let mut theme = Self::new();
+		theme
+			.set_background(preset_color("#1e1e1e"))
+			.set_text_color(preset_color("#e0e0e0"))
+			.set_border(true, &[Color(preset_color("#a0a0a0"))])
+			.set_grid(true, &[Color(preset_color("#505050")), LineStyle(Dot)])
+			.set_legend_style(false, false);
+		theme
+	}
+
+	/// Small serif text, inward ticks, no grid and a boxed legend, as commonly required by journals
+	pub fn publication() -> Self
+	{
+		let mut theme = Self::new();
+		theme
+			.set_font("Times", 10.)
+			.set_background(preset_color("white"))
+			.set_text_color(preset_color("black"))
+			.set_border(true, &[Color(preset_color("black")), LineWidth(1.)])
+			.set_grid(false, &[])
+			.set_ticks_inward(true)
+			.set_legend_style(true, true);
+		theme
+	}
+
+	/// Large text and thick lines that remain legible when projected
+	pub fn presentation() -> Self
+	{
+		let mut theme = Self::new();
+		theme
+			.set_font("Sans", 20.)
+			.set_background(preset_color("white"))
+			.set_text_color(preset_color("#202020"))
+			.set_border(true, &[Color(preset_color("#202020")), LineWidth(2.)])
+			.set_grid(true, &[Color(preset_color("#c8c8c8")), LineWidth(1.5)])
+			.set_ticks_inward(false)
+			.set_legend_style(false, true);
+		theme
+	}
+
+	/// Returns one of the built-in themes by name: `light`, `dark`, `publication` or
+	/// `presentation`. The lookup is case-insensitive.
+	pub fn by_name(name: &str) -> Option<Self>
+	{
+		match name.to_lowercase().as_str()
+		{
+			"light" => Some(Self::light()),
+			"dark" => Some(Self::dark()),
+			"publication" => Some(Self::publication()),
+			"presentation" => Some(Self::presentation()),
+			_ => None,
+		}
+	}
+
+	/// Parses a theme from a TOML document. Requires the `toml` feature.
+	///
+	/// All keys are optional. The recognized keys are as follows:
+	///
+	/// ```toml
+	/// # Start from one of the built-in themes, see `Theme::by_name`. The other keys are applied
+	/// # on top of it.
+	/// base = "dark"
+	/// background = "#1e1e1e"
+	/// text_color = "#e0e0e0"
+	///
+	/// [font]
+	/// family = "Helvetica"
+	/// size = 12
+	///
+	/// [border]
+	/// show = true
+	/// color = "gray"
+	/// width = 1.5
+	/// dash = "solid"
+	///
+	/// [grid]
+	/// show = true
+	/// color = "#505050"
+	/// width = 0.5
+	/// # One of solid, small_dot, dot, dash, dot_dash, dot_dot_dash, or a pattern like "-. ", see
+	/// # `DashPattern`.
+	/// dash = "dot"
+	///
+	/// [ticks]
+	/// inward = false
+	///
+	/// [legend]
+	/// box = true
+	/// opaque = true
+	/// ```
+	#[cfg(feature = "toml")]
+	pub fn from_toml(source: &str) -> Result<Self, ThemeError>
+	{
+		let table: toml::Table = source.parse().map_err(|e: toml::de::Error| {
+			let line = e
+				.span()
+				.map(|span| source[..span.start].matches('\n').count() + 1);
+			ThemeError::new(line, e.message().into())
+		})?;
+		let err = |message: String| ThemeError::new(None, message);
+
+		let mut theme = match table.get("base")
+		{
+			Some(base) =>
+			{
+				let name = toml_str("base", base).map_err(err)?;
+				Self::by_name(name).ok_or_else(|| err(format!("unknown theme `{name}`")))?
+			}
+			None => Self::new(),
+		};
+		for (name, value) in table.iter().filter(|(name, _)| *name != "base")
+		{
+			match value
+			{
+				toml::Value::Table(section) =>
+				{
+					for (key, value) in section
+					{
+						theme
+							.set_toml_value(&format!("{name}.{key}"), value)
+							.map_err(err)?;
+					}
+				}
+				_ => theme.set_toml_value(name, value).map_err(err)?,
+			}
+		}
+		Ok(theme)
+	}
+
+	/// Sets the default font of all the text
+	///
+	/// # Arguments
+	/// * `family` - Font family, or an empty string to keep the terminal's font family
+	/// * `size` - Font size in points
+	pub fn set_font(&mut self, family: &str, size: f64) -> &mut Self
+	{
+		self.font_family = Some(family.into());
+		self.font_size = Some(size);
+		self
+	}
+
+	/// Sets the color of the figure background
+	pub fn set_background(&mut self, color: ColorType<&str>) -> &mut Self
+	{
+		self.background = Some(color.to_one_way_owned());
+		self
+	}
+
+	/// Sets the color of the titles, axis labels, tick labels and the legend text
+	pub fn set_text_color(&mut self, color: ColorType<&str>) -> &mut Self
+	{
+		self.text_color = Some(color.to_one_way_owned());
+		self
+	}
+
+	/// Sets the border of the axes
+	///
+	/// # Arguments
+	/// * `show` - Whether to draw the border
+	/// * `options` - Array of PlotOption controlling the appearance of the border. Relevant options are:
+	///      * `Color` - Specifies the color of the border
+	///      * `LineStyle` - Specifies the style of the border
+	///      * `LineWidth` - Specifies the width of the border
+	pub fn set_border(&mut self, show: bool, options: &[PlotOption<&str>]) -> &mut Self
+	{
+		self.show_border = Some(show);
+		self.border_options = options.to_one_way_owned();
+		self
+	}
+
+	/// Sets the major grid of the X and Y axes
+	///
+	/// # Arguments
+	/// * `show` - Whether to draw the grid
+	/// * `options` - Array of PlotOption controlling the appearance of the grid lines. Relevant options are:
+	///      * `Color` - Specifies the color of the grid lines
+	///      * `LineStyle` - Specifies the style of the grid lines
+	///      * `LineWidth` - Specifies the width of the grid lines
+	pub fn set_grid(&mut self, show: bool, options: &[PlotOption<&str>]) -> &mut Self
+	{
+		self.show_grid = Some(show);
+		self.grid_options = options.to_one_way_owned();
+		self
+	}
+
+	/// Sets whether the ticks point into the plot area
+	pub fn set_ticks_inward(&mut self, inward: bool) -> &mut Self
+	{
+		self.ticks_inward = Some(inward);
+		self
+	}
+
+	/// Sets the style of the legend
+	///
+	/// # Arguments
+	/// * `boxed` - Whether to draw a box around the legend
+	/// * `opaque` - Whether to draw the legend over a background that hides the plot elements behind it
+	pub fn set_legend_style(&mut self, boxed: bool, opaque: bool) -> &mut Self
+	{
+		self.legend_box = Some(boxed);
+		self.legend_opaque = Some(opaque);
+		self
+	}
+
+	pub(crate) fn background(&self) -> Option<&ColorType>
+	{
+		self.background.as_ref()
+	}
+
+	pub(crate) fn show_border(&self) -> Option<bool>
+	{
+		self.show_border
+	}
+
+	pub(crate) fn border_options(&self) -> &[PlotOption<String>]
+	{
+		&self.border_options
+	}
+
+	pub(crate) fn show_grid(&self) -> bool
+	{
+		self.show_grid == Some(true)
+	}
+
+	pub(crate) fn grid_options(&self) -> &[PlotOption<String>]
+	{
+		&self.grid_options
+	}
+
+	#[cfg(feature = "toml")]
+	fn set_toml_value(&mut self, key: &str, value: &toml::Value) -> Result<(), String>
+	{
+		match key
+		{
+			"background" => self.background = Some(parse_color(toml_str(key, value)?)?),
+			"text_color" => self.text_color = Some(parse_color(toml_str(key, value)?)?),
+			"font.family" => self.font_family = Some(toml_str(key, value)?.into()),
+			"font.size" => self.font_size = Some(toml_f64(key, value)?),
+			"border.show" => self.show_border = Some(toml_bool(key, value)?),
+			"border.color" => set_option(
+				&mut self.border_options,
+				Color(parse_color(toml_str(key, value)?)?),
+			),
+			"border.width" =>
+			{
+				set_option(&mut self.border_options, LineWidth(toml_f64(key, value)?))
+			}
+			"border.dash" => set_option(
+				&mut self.border_options,
+				LineStyle(parse_dash_type(toml_str(key, value)?)?),
+			),
+			"grid.show" => self.show_grid = Some(toml_bool(key, value)?),
+			"grid.color" => set_option(
+				&mut self.grid_options,
+				Color(parse_color(toml_str(key, value)?)?),
+			),
+			"grid.width" => set_option(&mut self.grid_options, LineWidth(toml_f64(key, value)?)),
+			"grid.dash" => set_option(
+				&mut self.grid_options,
+				LineStyle(parse_dash_type(toml_str(key, value)?)?),
+			),
+			"ticks.inward" => self.ticks_inward = Some(toml_bool(key, value)?),
+			"legend.box" => self.legend_box = Some(toml_bool(key, value)?),
+			"legend.opaque" => self.legend_opaque = Some(toml_bool(key, value)?),
+			_ => return Err(format!("unknown key `{key}`")),
+		}
+		Ok(())
+	}
+
+	/// Writes the settings that apply to the whole figure, after the terminal is set
+	pub(crate) fn write_out_terminal_options(&self, w: &mut dyn Writer)
+	{
+		if self.font_family.is_some() || self.font_size.is_some()
+		{
+			writeln!(
+				w,
+				"set termoption font \"{},{}\"",
+				escape(self.font_family.as_deref().unwrap_or("")),
+				self.font_size.map(|s| s.to_string()).unwrap_or_default()
+			);
+		}
+	}
+
+	/// Writes the background of a page, to be undone with `reset_background` after the first axes
+	/// of the page is drawn
+	pub(crate) fn write_out_background(&self, w: &mut dyn Writer)
+	{
+		if let Some(ref color) = self.background
+		{
+			writeln!(
+				w,
+				"set object {} rectangle from screen 0,0 to screen 1,1 behind fillcolor {} fillstyle solid 1.0 noborder",
+				BACKGROUND_TAG,
+				color.command()
+			);
+		}
+	}
+
+	pub(crate) fn reset_background(&self, w: &mut dyn Writer)
+	{
+		if self.background.is_some()
+		{
+			writeln!(w, "unset object {}", BACKGROUND_TAG);
+		}
+	}
+
+	/// Writes the settings that apply to each axes
+	pub(crate) fn write_out_commands(&self, w: &mut dyn Writer)
+	{
+		if let Some(ref color) = self.text_color
+		{
+			let color = color.command();
+			for label in [
+				"title", "xlabel", "ylabel", "x2label", "y2label", "zlabel", "cblabel",
+			]
+			{
+				writeln!(w, "set {} textcolor {}", label, color);
+			}
+			writeln!(w, "set tics textcolor {}", color);
+		}
+		if let Some(inward) = self.ticks_inward
+		{
+			writeln!(w, "set tics {}", if inward { "in" } else { "out" });
+		}
+		if self.legend_box.is_some() || self.legend_opaque.is_some() || self.text_color.is_some()
+		{
+			w.write_str("set key");
+			if let Some(boxed) = self.legend_box
+			{
+				w.write_str(if boxed { " box" } else { " nobox" });
+			}
+			if let Some(opaque) = self.legend_opaque
+			{
+				w.write_str(if opaque { " opaque" } else { " noopaque" });
+			}
+			if let Some(ref color) = self.text_color
+			{
+				write!(w, " textcolor {}", color.command());
+			}
+			w.write_str("\n");
+		}
+	}
+
+	/// Undoes the settings written by `write_out_commands`, so they don't leak into the next axes
+	/// of a multiplot
+	pub(crate) fn reset_state(&self, w: &mut dyn Writer)
+	{
+		if self.text_color.is_some()
+		{
+			for label in [
+				"title", "xlabel", "ylabel", "x2label", "y2label", "zlabel", "cblabel",
+			]
+			{
+				writeln!(w, "set {} textcolor default", label);
+			}
+			w.write_str("set tics textcolor default\n");
+		}
+		if self.ticks_inward.is_some()
+		{
+			w.write_str("set tics in\n");
+		}
+		if self.legend_box.is_some() || self.legend_opaque.is_some() || self.text_color.is_some()
+		{
+			w.write_str("set key nobox noopaque textcolor default\n");
+		}
+	}
+}
+
+/// Replaces the option of the same kind as `option`, if any
+#[cfg(feature = "toml")]
+fn set_option(options: &mut Vec<PlotOption<String>>, option: PlotOption<String>)
+{
+	options.retain(|o| mem::discriminant(o) != mem::discriminant(&option));
+	options.push(option);
+}
+
+#[cfg(feature = "toml")]
+fn parse_color(color: &str) -> Result<ColorType, String>
+{
+	ColorType::parse(color).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "toml")]
+fn parse_dash_type(name: &str) -> Result<DashType, String>
+{
+	match name
+	{
+		"solid" => Ok(Solid),
+		"small_dot" => Ok(SmallDot),
+		"dot" => Ok(Dot),
+		"dash" => Ok(Dash),
+		"dot_dash" => Ok(DotDash),
+		"dot_dot_dash" => Ok(DotDotDash),
+		_ => DashType::pattern(name).map_err(|_| format!("unknown dash type `{name}`")),
+	}
+}
+
+#[cfg(feature = "toml")]
+fn toml_str<'l>(key: &str, value: &'l toml::Value) -> Result<&'l str, String>
+{
+	value
+		.as_str()
+		.ok_or_else(|| format!("`{key}` must be a string"))
+}
+
+#[cfg(feature = "toml")]
+fn toml_f64(key: &str, value: &toml::Value) -> Result<f64, String>
+{
+	match *value
+	{
+		toml::Value::Float(v) => Ok(v),
+		toml::Value::Integer(v) => Ok(v as f64),
+		_ => Err(format!("`{key}` must be a number")),
+	}
+}
+
+#[cfg(feature = "toml")]
+fn toml_bool(key: &str, value: &toml::Value) -> Result<bool, String>
+{
+	value
+		.as_bool()
+		.ok_or_else(|| format!("`{key}` must be a boolean"))
+}
+
+/// A color of the built-in styles, checked with `ColorType::parse`
+fn preset_color(color: &'static str) -> ColorType<&'static str>
+{
+	ColorType::parse(color)
+		.map(|_| ColorType::RGBString(color))
+		.unwrap()
+}
+
+#[test]
+fn style_cycle_test()
+{
+	let mut cycle = StyleCycle::new();
+	cycle
+		.set_colors(&["red".into(), "blue".into()])
+		.set_dash_types(&[Solid, Dash, Dot]);
+	assert_eq!(cycle.options(4), vec![Color("red".into()), LineStyle(Dash)]);
+	assert_eq!(StyleCycle::new().options(0), vec![]);
+}
+
+#[test]
+fn presets_test()
+{
+	// Builds all the presets, checking their colors.
+	StyleCycle::tableau10();
+	StyleCycle::okabe_ito();
+	StyleCycle::grayscale();
+	for name in ["light", "dark", "publication", "presentation"]
+	{
+		assert!(Theme::by_name(name).is_some());
+	}
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn theme_from_toml_test()
+{
+	let source = r##"
+		base = "dark" # comment
+		background = "#000000"
+
+		[grid]
+		dash = "dash"
+
+		[legend]
+		box = true
+		opaque = false
+	"##;
+	let mut expected = Theme::dark();
+	expected
+		.set_background("#000000".into())
+		.set_grid(true, &[Color("#505050".into()), LineStyle(Dash)])
+		.set_legend_style(true, false);
+	assert_eq!(Theme::from_toml(source).unwrap(), expected);
+
+	assert_eq!(Theme::from_toml("").unwrap(), Theme::new());
+	let mut expected = Theme::dark();
+	expected.set_text_color("red".into());
+	assert_eq!(
+		Theme::from_toml("text_color = \"red\"\nbase = \"dark\"").unwrap(),
+		expected
+	);
+	assert_eq!(
+		Theme::from_toml("[font]\nsize = \"big\"")
+			.unwrap_err()
+			.line(),
+		None
+	);
+	assert_eq!(
+		Theme::from_toml("[font]\nsize = ").unwrap_err().line(),
+		Some(2)
+	);
+	assert!(Theme::from_toml("base = \"nope\"").is_err());
+	assert!(Theme::from_toml("[grid]\ncolor = \"blu\"").is_err());
+}
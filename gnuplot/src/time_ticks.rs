@@ -0,0 +1,488 @@
+// Copyright (c) 2013-2014 by SiegeLord
+//
+// All rights reserved. Distributed under LGPL 3.0. For full terms see the file LICENSE.
+
+pub use self::TimeTicks::*;
+
+use crate::options::{Fix, Major, Tick};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const MONTH: f64 = 30.436875 * DAY;
+const YEAR: f64 = 365.2425 * DAY;
+
+/// Roughly the largest number of ticks `AutoTimeTicks` will place
+const MAX_AUTO_TICKS: f64 = 8.0;
+
+/// Largest number of ticks placed with an explicit spacing, beyond which `AutoTimeTicks` is used
+const MAX_TICKS: f64 = 1000.0;
+
+const MONTH_NAMES: [&str; 12] = [
+	"January",
+	"February",
+	"March",
+	"April",
+	"May",
+	"June",
+	"July",
+	"August",
+	"September",
+	"October",
+	"November",
+	"December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+	"Monday",
+	"Tuesday",
+	"Wednesday",
+	"Thursday",
+	"Friday",
+	"Saturday",
+	"Sunday",
+];
+
+/// Specifies where to place ticks on a time axis. Times are measured in seconds since
+/// 1970-01-01 00:00:00 UTC, which is what gnuplot uses for time axes.
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
+pub enum TimeTicks
+{
+	/// Picks one of the other tick placements based on the length of the range
+	AutoTimeTicks,
+	/// Every N seconds, at multiples of N seconds since the epoch
+	SecondTicks(u32),
+	/// Every N minutes, at multiples of N minutes since the epoch
+	MinuteTicks(u32),
+	/// Every N hours, at multiples of N hours since the epoch. This is aligned to midnight (UTC)
+	/// when N divides 24, otherwise the ticks fall at different hours on different days.
+	HourTicks(u32),
+	/// Every N days
+	DayTicks(u32),
+	/// At the start of every week (Monday)
+	WeekStartTicks,
+	/// At the start of every Nth month, aligned to the start of the year
+	MonthStartTicks(u32),
+	/// At the start of every Nth year, aligned to years divisible by N
+	YearStartTicks(u32),
+	/// At the start of every day from Monday to Friday
+	BusinessDayTicks,
+}
+
+impl TimeTicks
+{
+	/// Approximate spacing between the ticks, in seconds
+	fn spacing(&self) -> f64
+	{
+		match *self
+		{
+			AutoTimeTicks => 0.0,
+			SecondTicks(n) => n as f64,
+			MinuteTicks(n) => n as f64 * MINUTE,
+			HourTicks(n) => n as f64 * HOUR,
+			DayTicks(n) => n as f64 * DAY,
+			WeekStartTicks => 7.0 * DAY,
+			MonthStartTicks(n) => n as f64 * MONTH,
+			YearStartTicks(n) => n as f64 * YEAR,
+			BusinessDayTicks => DAY,
+		}
+	}
+
+	/// Picks the tick placement with the smallest spacing that results in a reasonable number of ticks
+	fn choose(min: f64, max: f64) -> TimeTicks
+	{
+		let span = (max - min).abs();
+		let candidates = [
+			SecondTicks(1),
+			SecondTicks(2),
+			SecondTicks(5),
+			SecondTicks(10),
+			SecondTicks(15),
+			SecondTicks(30),
+			MinuteTicks(1),
+			MinuteTicks(2),
+			MinuteTicks(5),
+			MinuteTicks(10),
+			MinuteTicks(15),
+			MinuteTicks(30),
+			HourTicks(1),
+			HourTicks(2),
+			HourTicks(3),
+			HourTicks(6),
+			HourTicks(12),
+			DayTicks(1),
+			DayTicks(2),
+			WeekStartTicks,
+			MonthStartTicks(1),
+			MonthStartTicks(2),
+			MonthStartTicks(3),
+			MonthStartTicks(6),
+		];
+		for candidate in candidates
+		{
+			if span / candidate.spacing() <= MAX_AUTO_TICKS
+			{
+				return candidate;
+			}
+		}
+		let mut years = 1;
+		loop
+		{
+			for mult in [1, 2, 5]
+			{
+				if span / (years * mult) as f64 / YEAR <= MAX_AUTO_TICKS
+				{
+					return YearStartTicks(years * mult);
+				}
+			}
+			years *= 10;
+		}
+	}
+
+	/// A format that is appropriate for these ticks over the range
+	fn default_format(&self, min: f64, max: f64) -> &'static str
+	{
+		let first = civil_from_days(days_floor(min));
+		let last = civil_from_days(days_floor(max));
+		match *self
+		{
+			AutoTimeTicks => Self::choose(min, max).default_format(min, max),
+			ticks => ticks.span_format(first == last, first.0 == last.0),
+		}
+	}
+
+	/// A format for these ticks when the range is not known, leaving `AutoTimeTicks` to gnuplot
+	pub(crate) fn unbounded_format(&self) -> Option<&'static str>
+	{
+		match *self
+		{
+			AutoTimeTicks => None,
+			ticks => Some(ticks.span_format(false, false)),
+		}
+	}
+
+	/// Tick placement arguments of `set xtics` that approximate these ticks, used when the range
+	/// is not known. gnuplot cannot align ticks to calendar months or skip weekends, so
+	/// `MonthStartTicks`, `YearStartTicks` and `BusinessDayTicks` use its automatic spacing.
+	pub(crate) fn gnuplot_spacing(&self) -> String
+	{
+		match *self
+		{
+			SecondTicks(n) | MinuteTicks(n) | HourTicks(n) | DayTicks(n) if n > 0 =>
+			{
+				format!(" {:.12e}", self.spacing())
+			}
+			// 1970-01-05 was the first Monday after the epoch.
+			WeekStartTicks => format!(" {:.12e}, {:.12e}", 4.0 * DAY, 7.0 * DAY),
+			_ => " autofreq".into(),
+		}
+	}
+
+	fn span_format(&self, same_day: bool, same_year: bool) -> &'static str
+	{
+		match *self
+		{
+			AutoTimeTicks => unreachable!(),
+			SecondTicks(_) =>
+			{
+				if same_day
+				{
+					"%H:%M:%S"
+				}
+				else
+				{
+					"%H:%M:%S\\n%b %d"
+				}
+			}
+			MinuteTicks(_) | HourTicks(_) =>
+			{
+				if same_day
+				{
+					"%H:%M"
+				}
+				else
+				{
+					"%H:%M\\n%b %d"
+				}
+			}
+			DayTicks(_) | WeekStartTicks | BusinessDayTicks =>
+			{
+				if same_year
+				{
+					"%b %d"
+				}
+				else
+				{
+					"%b %d\\n%Y"
+				}
+			}
+			MonthStartTicks(_) =>
+			{
+				if same_year
+				{
+					"%b"
+				}
+				else
+				{
+					"%b %Y"
+				}
+			}
+			YearStartTicks(_) => "%Y",
+		}
+	}
+}
+
+/// Computes the positions and labels of ticks on a time axis spanning from `min` to `max`. The
+/// result can be passed to `set_x_ticks_custom` and friends.
+///
+/// # Arguments
+/// * `min` - Start of the range, in seconds since the epoch
+/// * `max` - End of the range, in seconds since the epoch
+/// * `ticks` - Where to place the ticks
+/// * `format` - Format of the labels, see `format_time`. If `None`, a format appropriate for the
+///   tick spacing and the range is chosen.
+///
+/// If `ticks` would place more than 1000 ticks over the range, the spacing is picked as for
+/// `AutoTimeTicks` instead.
+pub fn time_ticks(
+	min: f64, max: f64, ticks: TimeTicks, format: Option<&str>,
+) -> Vec<Tick<f64, String>>
+{
+	let (min, max) = if min <= max { (min, max) } else { (max, min) };
+	let ticks = match ticks
+	{
+		AutoTimeTicks => TimeTicks::choose(min, max),
+		t if max - min > t.spacing() * MAX_TICKS => TimeTicks::choose(min, max),
+		t => t,
+	};
+	let format = format.unwrap_or_else(|| ticks.default_format(min, max));
+
+	tick_positions(min, max, ticks)
+		.into_iter()
+		.map(|t| Major(t, Fix(format_time(t, format))))
+		.collect()
+}
+
+fn tick_positions(min: f64, max: f64, ticks: TimeTicks) -> Vec<f64>
+{
+	let first_day = (min / DAY).ceil() as i64;
+	let last_day = days_floor(max);
+	let day_ticks = |keep: &dyn Fn(i64) -> bool| {
+		(first_day..=last_day)
+			.filter(|&d| keep(d))
+			.map(|d| d as f64 * DAY)
+			.collect()
+	};
+
+	match ticks
+	{
+		AutoTimeTicks => tick_positions(min, max, TimeTicks::choose(min, max)),
+		SecondTicks(_) | MinuteTicks(_) | HourTicks(_) =>
+		{
+			let step = ticks.spacing().max(1.0);
+			let first = (min / step).ceil() as i64;
+			let last = (max / step).floor() as i64;
+			(first..=last).map(|i| i as f64 * step).collect()
+		}
+		DayTicks(n) => day_ticks(&|d| d.rem_euclid(n.max(1) as i64) == 0),
+		WeekStartTicks => day_ticks(&|d| weekday(d) == 0),
+		BusinessDayTicks => day_ticks(&|d| weekday(d) < 5),
+		MonthStartTicks(n) =>
+		{
+			let n = n.max(1) as i64;
+			let (mut year, mut month, _) = civil_from_days(days_floor(min));
+			let mut ret = vec![];
+			loop
+			{
+				let day = days_from_civil(year, month, 1);
+				if day > last_day
+				{
+					break;
+				}
+				if day >= first_day && (month - 1) % n == 0
+				{
+					ret.push(day as f64 * DAY);
+				}
+				month += 1;
+				if month > 12
+				{
+					month = 1;
+					year += 1;
+				}
+			}
+			ret
+		}
+		YearStartTicks(n) =>
+		{
+			let n = n.max(1) as i64;
+			let first_year = civil_from_days(days_floor(min)).0;
+			let last_year = civil_from_days(last_day).0;
+			(first_year..=last_year)
+				.filter(|y| y.rem_euclid(n) == 0)
+				.map(|y| days_from_civil(y, 1, 1))
+				.filter(|&d| d >= first_day)
+				.map(|d| d as f64 * DAY)
+				.collect()
+		}
+	}
+}
+
+/// Formats a time (in seconds since the epoch, UTC) using a subset of the strftime format
+/// specifiers. The supported specifiers are:
+///
+/// * `%Y` - Year
+/// * `%y` - Year without the century (00-99)
+/// * `%m` - Month (01-12)
+/// * `%b` - Abbreviated month name
+/// * `%B` - Full month name
+/// * `%d` - Day of the month (01-31)
+/// * `%j` - Day of the year (001-366)
+/// * `%a` - Abbreviated weekday name
+/// * `%A` - Full weekday name
+/// * `%H` - Hour (00-23)
+/// * `%M` - Minute (00-59)
+/// * `%S` - Second (00-59)
+/// * `%%` - A literal `%`
+///
+/// Since gnuplot treats custom tick labels as format strings, a literal `%` is written out as `%%`.
+pub fn format_time(t: f64, format: &str) -> String
+{
+	let days = days_floor(t);
+	let seconds = (t - days as f64 * DAY).floor() as i64;
+	let (year, month, day) = civil_from_days(days);
+	let month_name = MONTH_NAMES[month as usize - 1];
+	let weekday_name = WEEKDAY_NAMES[weekday(days) as usize];
+
+	let mut ret = String::new();
+	let mut chars = format.chars();
+	while let Some(c) = chars.next()
+	{
+		if c != '%'
+		{
+			ret.push(c);
+			continue;
+		}
+		match chars.next()
+		{
+			Some('Y') => ret.push_str(&year.to_string()),
+			Some('y') => ret.push_str(&format!("{:02}", year.rem_euclid(100))),
+			Some('m') => ret.push_str(&format!("{:02}", month)),
+			Some('b') => ret.push_str(&month_name[..3]),
+			Some('B') => ret.push_str(month_name),
+			Some('d') => ret.push_str(&format!("{:02}", day)),
+			Some('j') => ret.push_str(&format!("{:03}", days - days_from_civil(year, 1, 1) + 1)),
+			Some('a') => ret.push_str(&weekday_name[..3]),
+			Some('A') => ret.push_str(weekday_name),
+			Some('H') => ret.push_str(&format!("{:02}", seconds / 3600)),
+			Some('M') => ret.push_str(&format!("{:02}", seconds / 60 % 60)),
+			Some('S') => ret.push_str(&format!("{:02}", seconds % 60)),
+			Some('%') => ret.push_str("%%"),
+			Some(c) =>
+			{
+				ret.push_str("%%");
+				ret.push(c);
+			}
+			None => ret.push_str("%%"),
+		}
+	}
+	ret
+}
+
+fn days_floor(t: f64) -> i64
+{
+	(t / DAY).floor() as i64
+}
+
+/// Day of the week, 0 is Monday
+fn weekday(days: i64) -> i64
+{
+	// 1970-01-01 was a Thursday.
+	(days + 3).rem_euclid(7)
+}
+
+/// Days since the epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64
+{
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
+
+/// Inverse of `days_from_civil`, returns `(year, month, day)`
+fn civil_from_days(days: i64) -> (i64, i64, i64)
+{
+	let days = days + 719468;
+	let era = days.div_euclid(146097);
+	let day_of_era = days - era * 146097;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let mp = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+#[test]
+fn time_ticks_test()
+{
+	assert_eq!(civil_from_days(0), (1970, 1, 1));
+	assert_eq!(days_from_civil(2000, 3, 1), 11017);
+	assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+	assert_eq!(
+		format_time(951782400.0 + 3723.0, "%Y-%m-%d %a %H:%M:%S %j"),
+		"2000-02-29 Tue 01:02:03 060"
+	);
+
+	let positions = |ticks: Vec<Tick<f64, String>>| {
+		ticks
+			.iter()
+			.map(|t| match *t
+			{
+				Major(p, _) => format_time(p, "%Y-%m-%d"),
+				_ => unreachable!(),
+			})
+			.collect::<Vec<_>>()
+	};
+
+	// 2024-01-01 is a Monday.
+	let start = days_from_civil(2024, 1, 1) as f64 * DAY;
+	assert_eq!(
+		positions(time_ticks(
+			start - 1.0,
+			start + 9.0 * DAY,
+			BusinessDayTicks,
+			None
+		)),
+		[
+			"2024-01-01",
+			"2024-01-02",
+			"2024-01-03",
+			"2024-01-04",
+			"2024-01-05",
+			"2024-01-08",
+			"2024-01-09",
+			"2024-01-10"
+		]
+	);
+	assert_eq!(
+		positions(time_ticks(
+			start,
+			start + 200.0 * DAY,
+			MonthStartTicks(3),
+			None
+		)),
+		["2024-01-01", "2024-04-01", "2024-07-01"]
+	);
+	assert_eq!(TimeTicks::choose(0.0, 3.0 * HOUR), MinuteTicks(30));
+	assert_eq!(TimeTicks::choose(0.0, 30.0 * YEAR), YearStartTicks(5));
+	// Too many ticks for the range.
+	assert_eq!(
+		time_ticks(start, start + 10.0 * YEAR, SecondTicks(1), None).len(),
+		time_ticks(start, start + 10.0 * YEAR, AutoTimeTicks, None).len()
+	);
+}
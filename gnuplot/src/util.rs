@@ -52,57 +52,13 @@ macro_rules! first_opt_default
 macro_rules! generate_data {
 	($options: ident, $( $d:ident ),*) => {
 		{
-			let mut widths = None;
-			first_opt! {$options,
-				BoxWidth(ref w) =>
-				{
-					widths = Some(w);
-				}
-			}
-
-			let mut c_data = None;
-			first_opt! {$options,
-				Color(ref color) =>
-				{
-					if color.is_variable() {
-						c_data = Some(color.data());
-					}
-				}
-			}
-
-			if let Some(widths) = widths {
-				if let Some(c_values) = c_data {
-					generate_data_inner!(
-						$(
-							$d,
-						)*
-						widths,
-						c_values
-					)
-				} else {
-					generate_data_inner!(
-						$(
-							$d,
-						)*
-						widths
-					)
-				}
-			} else {
-				if let Some(c_values) = c_data {
-					generate_data_inner!(
-						$(
-							$d,
-						)*
-						c_values
-					)
-				} else {
-					generate_data_inner!(
-						$(
-							$d,
-						)*
-					)
-				}
-			}
+			let (data, num_rows, num_cols) = generate_data_inner!($($d, )*);
+			$crate::util::append_columns(
+				data,
+				num_rows,
+				num_cols,
+				&$crate::util::variable_columns(&$options[..]),
+			)
 		}
 	}
 }
@@ -155,6 +111,10 @@ macro_rules! count_data {
     ($($data:tt)*) => {0usize $(+ replace_expr!($data 1usize))*};
 }
 
+use crate::axes_common::char_to_symbol;
+use crate::options::*;
+use std::fmt::{Debug, Display};
+
 pub(crate) trait OneWayOwned
 {
 	type Output;
@@ -170,6 +130,64 @@ impl<'l, T: OneWayOwned> OneWayOwned for &'l [T]
 	}
 }
 
+/// Returns the extra data columns requested by the plot options, in the order gnuplot expects
+/// them: box widths, point sizes, point types and finally colors.
+pub(crate) fn variable_columns<T: Display + Debug>(options: &[PlotOption<T>]) -> Vec<Vec<f64>>
+{
+	let mut columns = vec![];
+	first_opt! {options,
+		BoxWidth(ref w) =>
+		{
+			columns.push(w.clone());
+		}
+	}
+	if let Some(sizes) = options.iter().find_map(|o| match o
+	{
+		VariablePointSize(s) => Some(s.clone()),
+		VariablePointArea(a) => Some(a.iter().map(|a| a.sqrt()).collect()),
+		_ => None,
+	})
+	{
+		columns.push(sizes);
+	}
+	first_opt! {options,
+		VariablePointSymbol(ref s) =>
+		{
+			columns.push(s.iter().map(|&c| char_to_symbol(c) as f64).collect());
+		}
+	}
+	first_opt! {options,
+		Color(ref color) =>
+		{
+			if color.is_variable()
+			{
+				columns.push(color.data());
+			}
+		}
+	}
+	columns
+}
+
+/// Appends `columns` to row-major `data`, truncating it to the shortest column. Returns the new
+/// `(data, num_rows, num_cols)`.
+pub(crate) fn append_columns(
+	data: Vec<f64>, num_rows: usize, num_cols: usize, columns: &[Vec<f64>],
+) -> (Vec<f64>, usize, usize)
+{
+	if columns.is_empty()
+	{
+		return (data, num_rows, num_cols);
+	}
+	let num_rows = columns.iter().map(|c| c.len()).fold(num_rows, usize::min);
+	let mut new_data = Vec::with_capacity(num_rows * (num_cols + columns.len()));
+	for (i, row) in data.chunks(num_cols).take(num_rows).enumerate()
+	{
+		new_data.extend_from_slice(row);
+		new_data.extend(columns.iter().map(|c| c[i]));
+	}
+	(new_data, num_rows, num_cols + columns.len())
+}
+
 pub(crate) fn escape(s: &str) -> String
 {
 	let mut res = String::with_capacity(s.len());
@@ -201,3 +219,22 @@ fn escape_test()
 	assert_eq!(r"\n", escape("\n"));
 	assert_eq!(r"\`", escape("`"));
 }
+
+#[test]
+fn variable_columns_test()
+{
+	let options: Vec<PlotOption<&str>> = vec![
+		Color(crate::VariableIndex(vec![1, 2, 3])),
+		VariablePointArea(vec![4., 9.]),
+		VariablePointSymbol(vec!['o', 'S', 'x']),
+	];
+	let columns = variable_columns(&options);
+	assert_eq!(
+		columns,
+		vec![vec![2., 3.], vec![6., 5., 2.], vec![1., 2., 3.]]
+	);
+	assert_eq!(
+		append_columns(vec![0., 10., 1., 11., 2., 12.], 3, 2, &columns),
+		(vec![0., 10., 2., 6., 1., 1., 11., 3., 5., 2.], 2, 5)
+	);
+}